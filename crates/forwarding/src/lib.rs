@@ -20,6 +20,21 @@ pub fn forward_trait(attribs:TokenStream, item:TokenStream) -> TokenStream
     ).into()
 }
 
+#[proc_macro_attribute]
+pub fn forward_partial(attribs:TokenStream, item:TokenStream) -> TokenStream
+{
+    if let Some(attrib) = proc_macro2::TokenStream::from(attribs).into_iter().next() {
+        return syn::Error::new(attrib.span(), "The `forward_partial` attribute doesn't take arguments.").into_compile_error().into();
+    }
+
+    let parse_status = syn::parse2::<forwarding_gen::ForwardingPartialImplInput>(item.into());
+    parse_status.map(
+        |partial_input| partial_input.generate_macro_code()
+    ).unwrap_or_else(
+        |err| err.into_compile_error()
+    ).into()
+}
+
 #[proc_macro_derive(Forwarding, attributes(forward_derive, forwarding_mod_path))]
 pub fn forwarding_derive(input:TokenStream) -> TokenStream
 {
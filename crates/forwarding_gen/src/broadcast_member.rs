@@ -0,0 +1,204 @@
+/*
+Hereditary
+Autors: Francisco Leon <https://github.com/superoptimo>
+License Apache-2.0
+*/
+
+use proc_macro2::token_stream::TokenStream as TkStream;
+use quote::ToTokens;
+use syn::{Ident, Type};
+
+use crate::forwarding_options::ForwardingOptions;
+use crate::method_member_adapter::{ForwardingMember, MethodAdaptError, MethodSignatureParts, ReceiverKind};
+
+/// `aggregate(method = sum)` adds the values returned by the elements
+pub const FORWARD_AGGREGATE_SUM: &str = "sum";
+/// `aggregate(method = min)` returns the least value returned by the elements of a method that returns `Option`,
+/// skipping the elements that return `None`, or `None` when no element returns a value.
+pub const FORWARD_AGGREGATE_MIN: &str = "min";
+/// `aggregate(method = max)` returns the greatest value returned by the elements of a method that returns `Option`,
+/// skipping the elements that return `None`, or `None` when no element returns a value.
+pub const FORWARD_AGGREGATE_MAX: &str = "max";
+/// `aggregate(method = all)` checks that every element returns `true`
+pub const FORWARD_AGGREGATE_ALL: &str = "all";
+/// `aggregate(method = any)` checks that some element returns `true`
+pub const FORWARD_AGGREGATE_ANY: &str = "any";
+/// `aggregate(method = collect)` concatenates the collections returned by the elements
+pub const FORWARD_AGGREGATE_COLLECT: &str = "collect";
+
+/// Collections whose elements receive the broadcast methods
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CollectionKind
+{
+    /// arrays, slices, `Vec`, `VecDeque` and `LinkedList`, whose elements are iterated
+    Sequence,
+    /// `HashMap` and `BTreeMap`, whose values are iterated
+    Map
+}
+
+/// Kind of the collection of a broadcast member and the type of its elements,
+/// like `T` in `Vec<T>`, `[T; N]` or `HashMap<K, T>`.
+pub(crate) fn collection_parts(member_ty:&Type) -> Option<(CollectionKind, &Type)>
+{
+    match member_ty {
+        Type::Array(tarray) => Some((CollectionKind::Sequence, tarray.elem.as_ref())),
+        Type::Slice(tslice) => Some((CollectionKind::Sequence, tslice.elem.as_ref())),
+        Type::Path(tpath) => {
+            let last = tpath.path.segments.last()?;
+            let syn::PathArguments::AngleBracketed(args) = &last.arguments else {return None};
+            let mut type_args = args.args.iter().filter_map(|arg| match arg {
+                syn::GenericArgument::Type(type_arg) => Some(type_arg),
+                _ => None
+            });
+
+            if ["Vec", "VecDeque", "LinkedList"].iter().any(|sequence_name| last.ident == sequence_name) {
+                type_args.next().map(|element_ty| (CollectionKind::Sequence, element_ty))
+            }
+            else if last.ident == "HashMap" || last.ident == "BTreeMap" {
+                type_args.nth(1).map(|element_ty| (CollectionKind::Map, element_ty))
+            }
+            else {None}
+        },
+        _ => None
+    }
+}
+
+/// Calls to the elements of a collection member, whose results are combined by the aggregators of the methods
+pub(crate) struct BroadcastProxy<'a>
+{
+    pub(crate) options:&'a ForwardingOptions
+}
+
+impl BroadcastProxy<'_>
+{
+    /// Iterator over the elements of the submember in the form required by the receiver:
+    /// `iter()`, `iter_mut()` and `into_iter()` for sequences, or `values()`, `values_mut()` and `into_values()` for maps.
+    /// Collections are assumed to be sequences when the type of the submember isn't declared.
+    pub(crate) fn generate_elements(
+        &self,
+        submember:&ForwardingMember,
+        receiver_kind:ReceiverKind,
+        method_name:&Ident
+    ) -> Result<TkStream, MethodAdaptError>
+    {
+        let member = &submember.member;
+        let collection_kind = submember.member_ty.as_ref().and_then(collection_parts).map_or(
+            CollectionKind::Sequence, |(collection_kind, _)| collection_kind
+        );
+
+        let iter_fn = match (collection_kind, receiver_kind) {
+            (CollectionKind::Sequence, ReceiverKind::Ref) => quote::quote!(iter),
+            (CollectionKind::Sequence, ReceiverKind::RefMut) => quote::quote!(iter_mut),
+            (CollectionKind::Sequence, ReceiverKind::Value) => quote::quote!(into_iter),
+            (CollectionKind::Map, ReceiverKind::Ref) => quote::quote!(values),
+            (CollectionKind::Map, ReceiverKind::RefMut) => quote::quote!(values_mut),
+            (CollectionKind::Map, ReceiverKind::Value) => quote::quote!(into_values),
+            _ => {
+                return Err(MethodAdaptError::Unforwardable(method_name.span(), format!(
+                    "Method `{}` cannot be broadcast to the elements of the field `{}`, as it requires a `self`, `&self` or `&mut self` receiver.",
+                    method_name, submember.member_name()
+                )));
+            }
+        };
+        Ok(quote::quote!($self_token.#member.#iter_fn()))
+    }
+
+    /// Member that stands for each element of the collection, with the type of the elements
+    pub(crate) fn element_member(&self, submember:&ForwardingMember) -> ForwardingMember
+    {
+        ForwardingMember{
+            member:submember.member.clone(),
+            member_ty:submember.member_ty.as_ref().and_then(collection_parts).map(|(_, element_ty)| element_ty.clone())
+        }
+    }
+
+    /// Argument that passes each element to the trait method, dereferencing the elements stored behind a pointer
+    pub(crate) fn generate_element_arg(&self, submember:&ForwardingMember, receiver_kind:ReceiverKind) -> TkStream
+    {
+        let peeled = receiver_kind.peeled_member_type(&self.element_member(submember)).is_some();
+        match (receiver_kind, peeled) {
+            (ReceiverKind::Ref, true) => quote::quote!(&**__element),
+            (ReceiverKind::RefMut, true) => quote::quote!(&mut **__element),
+            (_, true) => quote::quote!(*__element),
+            (_, false) => quote::quote!(__element)
+        }
+    }
+
+    /// Arguments of the method for each element. Mutable references are reborrowed, and the rest of the arguments are cloned.
+    pub(crate) fn generate_parameters(&self, signature_parts:&MethodSignatureParts) -> Vec<TkStream>
+    {
+        signature_parts.parameter_ids.iter().zip(signature_parts.parameter_types()).map(|(param_id, param_ty)| match param_ty {
+            Type::Reference(tref) if tref.mutability.is_some() => quote::quote!(&mut *#param_id),
+            _ => quote::quote!(::core::clone::Clone::clone(&#param_id))
+        }).collect()
+    }
+
+    /// Iterator adaptor that combines the values returned by the elements, following the aggregator of the method.
+    /// It's `None` for the methods that don't return a value, whose calls are just repeated for every element.
+    pub(crate) fn generate_aggregation(&self, method_sig:&syn::Signature) -> Result<Option<TkStream>, MethodAdaptError>
+    {
+        let method_name = &method_sig.ident;
+        let returns_value = match &method_sig.output {
+            syn::ReturnType::Default => false,
+            syn::ReturnType::Type(_, output_ty) => !matches!(output_ty.as_ref(), Type::Tuple(ttuple) if ttuple.elems.is_empty())
+        };
+
+        let aggregate = self.options.aggregate.iter().find(|aggregate| aggregate.method == *method_name);
+        let aggregator = match (aggregate, returns_value) {
+            (None, false) => { return Ok(None); },
+            (Some(aggregate), true) => &aggregate.aggregator,
+            (Some(_), false) => {
+                return Err(MethodAdaptError::Unforwardable(method_name.span(), format!(
+                    "Method `{}` doesn't return a value, so it cannot be aggregated.", method_name
+                )));
+            },
+            (None, true) => {
+                return Err(MethodAdaptError::Unforwardable(method_name.span(), format!(
+                    "Method `{}` returns a value, so it requires an aggregator for the values of the elements, as in `aggregate({} = sum)`.",
+                    method_name, method_name
+                )));
+            }
+        };
+
+        if method_sig.asyncness.is_some() {
+            return Err(MethodAdaptError::Unforwardable(method_name.span(), format!(
+                "Method `{}` is asynchronous, so the values of the elements cannot be aggregated.", method_name
+            )));
+        }
+
+        // the least or greatest value is missing for empty collections, so it's only returned as an `Option`
+        let optional_output = matches!(&method_sig.output, syn::ReturnType::Type(_, output_ty) if matches!(
+            output_ty.as_ref(), Type::Path(tpath) if tpath.path.segments.last().is_some_and(|last| last.ident == "Option")
+        ));
+        if !optional_output && (aggregator.is_ident(FORWARD_AGGREGATE_MIN) || aggregator.is_ident(FORWARD_AGGREGATE_MAX)) {
+            return Err(MethodAdaptError::Unforwardable(method_name.span(), format!(
+                "Method `{}` is aggregated with `{}`, which requires returning an `Option` for the empty collections.",
+                method_name, aggregator.to_token_stream()
+            )));
+        }
+
+        let aggregation = if aggregator.is_ident(FORWARD_AGGREGATE_SUM) {
+            quote::quote!(.sum())
+        }
+        else if aggregator.is_ident(FORWARD_AGGREGATE_MIN) {
+            quote::quote!(.flatten().reduce(|__min, __value| if __value < __min {__value} else {__min}))
+        }
+        else if aggregator.is_ident(FORWARD_AGGREGATE_MAX) {
+            quote::quote!(.flatten().reduce(|__max, __value| if __value > __max {__value} else {__max}))
+        }
+        else if aggregator.is_ident(FORWARD_AGGREGATE_ALL) {
+            quote::quote!(.all(::core::convert::identity))
+        }
+        else if aggregator.is_ident(FORWARD_AGGREGATE_ANY) {
+            quote::quote!(.any(::core::convert::identity))
+        }
+        else if aggregator.is_ident(FORWARD_AGGREGATE_COLLECT) {
+            quote::quote!(.flatten().collect())
+        }
+        else {
+            let fold_fn = aggregator.to_token_stream();
+            quote::quote!(.fold(::core::default::Default::default(), #fold_fn))
+        };
+        Ok(Some(aggregation))
+    }
+}
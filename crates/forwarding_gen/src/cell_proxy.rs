@@ -0,0 +1,219 @@
+/*
+Hereditary
+Autors: Francisco Leon <https://github.com/superoptimo>
+License Apache-2.0
+*/
+
+use proc_macro2::token_stream::TokenStream as TkStream;
+use syn::fold::Fold;
+use syn::{Ident, Type};
+
+use crate::method_member_adapter::{ForwardingMember, MethodAdaptError, ReceiverKind, smart_pointer_parts};
+
+/// `cell = refcell` borrows the component from a `RefCell`
+pub const FORWARD_CELL_REFCELL: &str = "refcell";
+/// `cell = mutex` locks the component in a `Mutex`
+pub const FORWARD_CELL_MUTEX: &str = "mutex";
+/// `cell = rwlock` reads or writes the component in a `RwLock`
+pub const FORWARD_CELL_RWLOCK: &str = "rwlock";
+/// `on_error = panic` panics when the cell cannot be borrowed, or the lock is poisoned
+pub const FORWARD_ON_ERROR_PANIC: &str = "panic";
+
+/// Cell types that guard the access to a component with interior mutability
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CellKind
+{
+    RefCell,
+    Mutex,
+    RwLock
+}
+
+impl CellKind
+{
+    /// Cell kind named by the `cell` option
+    pub(crate) fn from_option(cell:&syn::Path) -> Option<Self>
+    {
+        if cell.is_ident(FORWARD_CELL_REFCELL) {Some(Self::RefCell)}
+        else if cell.is_ident(FORWARD_CELL_MUTEX) {Some(Self::Mutex)}
+        else if cell.is_ident(FORWARD_CELL_RWLOCK) {Some(Self::RwLock)}
+        else {None}
+    }
+
+    fn type_name(&self) -> &'static str
+    {
+        match self {
+            Self::RefCell => "RefCell",
+            Self::Mutex => "Mutex",
+            Self::RwLock => "RwLock"
+        }
+    }
+
+    fn type_path(&self) -> TkStream
+    {
+        match self {
+            Self::RefCell => quote::quote!(::core::cell::RefCell),
+            Self::Mutex => quote::quote!(::std::sync::Mutex),
+            Self::RwLock => quote::quote!(::std::sync::RwLock)
+        }
+    }
+
+    /// Guard function for the receiver, and if it returns a `Result` that reports the borrow errors or the poisoning.
+    /// The fallible `RefCell` borrows are only called when errors are returned by the `on_error` function.
+    fn guard_function(&self, receiver_kind:ReceiverKind, fallible_borrow:bool) -> (TkStream, bool)
+    {
+        match (self, receiver_kind, fallible_borrow) {
+            (Self::RefCell, ReceiverKind::Ref, false) => (quote::quote!(borrow), false),
+            (Self::RefCell, ReceiverKind::Ref, true) => (quote::quote!(try_borrow), true),
+            (Self::RefCell, _, false) => (quote::quote!(borrow_mut), false),
+            (Self::RefCell, _, true) => (quote::quote!(try_borrow_mut), true),
+            (Self::Mutex, _, _) => (quote::quote!(lock), true),
+            (Self::RwLock, ReceiverKind::Ref, _) => (quote::quote!(read), true),
+            (Self::RwLock, _, _) => (quote::quote!(write), true)
+        }
+    }
+}
+
+/// Type of the component stored in the cell of a field, like `T` in `RefCell<T>` or `Arc<Mutex<T>>`.
+/// The cell can be stored behind a `Box`, `Rc` or `Arc`.
+pub(crate) fn peel_cell_type(member_ty:&Type) -> Option<&Type>
+{
+    let (pointer_name, pointee) = smart_pointer_parts(member_ty)?;
+    if pointer_name == "RefCell" || pointer_name == "Mutex" || pointer_name == "RwLock" {
+        return Some(pointee);
+    }
+
+    if pointer_name == "Box" || pointer_name == "Rc" || pointer_name == "Arc" {
+        return peel_cell_type(pointee);
+    }
+    None
+}
+
+/// Detects borrowed types in the output of a method, which cannot outlive the guard of the cell
+struct BorrowFinder
+{
+    found:bool
+}
+
+impl Fold for BorrowFinder
+{
+    fn fold_type(&mut self, ty: Type) -> Type {
+        if matches!(&ty, Type::Reference(_) | Type::ImplTrait(_)) {
+            self.found = true;
+            return ty;
+        }
+        syn::fold::fold_type(self, ty)
+    }
+
+    fn fold_lifetime(&mut self, lifetime: syn::Lifetime) -> syn::Lifetime {
+        if lifetime.ident != "static" {
+            self.found = true;
+        }
+        lifetime
+    }
+}
+
+pub(crate) fn type_borrows(ty:&Type) -> bool
+{
+    let mut finder = BorrowFinder{found:false};
+    finder.fold_type(ty.clone());
+    finder.found
+}
+
+/// Access to a component with interior mutability, through the guard of its cell
+pub(crate) struct CellProxy<'a>
+{
+    pub(crate) kind:CellKind,
+    /// function that receives the borrow or poison error, and whose result is returned by the method
+    pub(crate) on_error:Option<&'a syn::Path>
+}
+
+impl CellProxy<'_>
+{
+    /// Checks that the method can be called through the guard: it requires a `&self`, `&mut self` or `self` receiver,
+    /// and the output cannot borrow from the guard.
+    pub(crate) fn check_method(&self, method_sig:&syn::Signature, receiver_kind:ReceiverKind) -> Result<(), MethodAdaptError>
+    {
+        let method_name = &method_sig.ident;
+        if !matches!(receiver_kind, ReceiverKind::Ref | ReceiverKind::RefMut | ReceiverKind::Value) {
+            return Err(MethodAdaptError::Unforwardable(method_name.span(), format!(
+                "Method `{}` cannot be forwarded through a `{}`, as it requires a `self`, `&self` or `&mut self` receiver.",
+                method_name, self.kind.type_name()
+            )));
+        }
+
+        match &method_sig.output {
+            syn::ReturnType::Type(_, output_ty) if type_borrows(output_ty) => Err(MethodAdaptError::Unforwardable(method_name.span(), format!(
+                "Method `{}` returns a borrowed value, which would outlive the guard of the `{}` that is released when the method returns.",
+                method_name, self.kind.type_name()
+            ))),
+            _ => Ok(())
+        }
+    }
+
+    /// Declares `__cell_guard`, the guard of the cell for borrowing receivers, or the component moved out
+    /// of the cell for `self` receivers. Errors panic, or return the value of the `on_error` function.
+    /// `cell_ref` borrows the cell, which is the submember (`&self.member`) unless it's reached in another way.
+    pub(crate) fn generate_guard_decl(
+        &self,
+        submember:&ForwardingMember,
+        cell_ref:TkStream,
+        receiver_kind:ReceiverKind,
+        method_name:&Ident
+    ) -> Result<TkStream, MethodAdaptError>
+    {
+        let member = &submember.member;
+        let cell_path = self.kind.type_path();
+
+        let (cell_call, fallible) = match receiver_kind {
+            ReceiverKind::Value => {
+                // only cells stored by value can be unwrapped, which is assumed when the type isn't declared
+                let stored_by_value = submember.member_ty.as_ref().is_none_or(|member_ty| smart_pointer_parts(member_ty).is_some_and(
+                    |(pointer_name, _)| pointer_name == self.kind.type_name()
+                ));
+                if !stored_by_value {
+                    return Err(MethodAdaptError::Unforwardable(method_name.span(), format!(
+                        "Method `{}` takes `self`, so it requires the field `{}` to be a `{}` stored by value.",
+                        method_name, submember.member_name(), self.kind.type_name()
+                    )));
+                }
+                (quote::quote!(#cell_path::into_inner($self_token.#member)), self.kind != CellKind::RefCell)
+            },
+            _ => {
+                let (guard_fn, fallible) = self.kind.guard_function(receiver_kind, self.on_error.is_some());
+                (quote::quote!(#cell_path::#guard_fn(#cell_ref)), fallible)
+            }
+        };
+
+        let mutability = if receiver_kind == ReceiverKind::RefMut {Some(quote::quote!(mut))} else {None};
+        if !fallible {
+            return Ok(quote::quote!(let #mutability __cell_guard = #cell_call;));
+        }
+
+        let error_handling = match self.on_error {
+            Some(on_error) => quote::quote!(return #on_error(__cell_error)),
+            None => {
+                let panic_message = format!(
+                    "the `{}` of the field `{}` is poisoned: {{}}", self.kind.type_name(), submember.member_name()
+                );
+                quote::quote!(::core::panic!(#panic_message, __cell_error))
+            }
+        };
+
+        Ok(quote::quote!(
+            let #mutability __cell_guard = match #cell_call {
+                ::core::result::Result::Ok(__cell_guard) => __cell_guard,
+                ::core::result::Result::Err(__cell_error) => { #error_handling }
+            };
+        ))
+    }
+
+    /// Argument that passes the component to the trait method in the form required by the receiver
+    pub(crate) fn generate_component_arg(&self, receiver_kind:ReceiverKind) -> TkStream
+    {
+        match receiver_kind {
+            ReceiverKind::Ref => quote::quote!(&*__cell_guard),
+            ReceiverKind::RefMut => quote::quote!(&mut *__cell_guard),
+            _ => quote::quote!(__cell_guard)
+        }
+    }
+}
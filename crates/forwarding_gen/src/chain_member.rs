@@ -0,0 +1,211 @@
+/*
+Hereditary
+Autors: Francisco Leon <https://github.com/superoptimo>
+License Apache-2.0
+*/
+
+use proc_macro2::token_stream::TokenStream as TkStream;
+use syn::parse::Parse;
+use syn::{Signature, Type};
+
+use crate::broadcast_member::BroadcastProxy;
+use crate::forwarding_options::ForwardingOptions;
+use crate::method_member_adapter::{ForwardingMember, MethodAdaptError, MethodSignatureParts, ReceiverKind, generate_component_call};
+
+/// `chain_error = first` returns the error of the first link when no link answers
+pub const FORWARD_CHAIN_ERROR_FIRST: &str = "first";
+/// `chain_error = last` returns the error of the last link when no link answers
+pub const FORWARD_CHAIN_ERROR_LAST: &str = "last";
+
+/// Member that is tried by the chained methods after the designated one.
+///
+/// Its syntax is `member` or `member: Type`, and `..member` tries the elements of a collection member in order.
+#[derive(Clone)]
+pub struct ChainLink
+{
+    pub member:ForwardingMember,
+    pub elements:bool
+}
+
+impl Parse for ChainLink
+{
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let elements = input.peek(syn::Token![..]);
+        if elements {
+            let _dots = input.parse::<syn::Token![..]>()?;
+        }
+        let member:ForwardingMember = input.parse()?;
+        Ok(Self{member, elements})
+    }
+}
+
+impl quote::ToTokens for ChainLink
+{
+    fn to_tokens(&self, tokens: &mut TkStream) {
+        let member = &self.member;
+        let dots = if self.elements {Some(quote::quote!(..))} else {None};
+        tokens.extend(quote::quote!(#dots #member));
+    }
+}
+
+/// Parses the comma separated list of links inside `chain(..)`
+pub(crate) fn parse_chain(input: syn::parse::ParseStream) -> syn::Result<Vec<ChainLink>>
+{
+    let links_content;
+    let _parens = syn::parenthesized!(links_content in input);
+    let links = links_content.parse_terminated(ChainLink::parse, syn::Token![,])?;
+    Ok(links.into_iter().collect())
+}
+
+/// Outputs that tell if a link answered the method
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ChainOutcome
+{
+    /// `Some(_)` answers, and `None` is returned when no link answers
+    Option,
+    /// `Ok(_)` answers, and the errors of the links are combined by `chain_error` when no link answers
+    Result,
+    /// `true` answers, and `false` is returned when no link answers
+    Bool
+}
+
+impl ChainOutcome
+{
+    fn from_output(output:&syn::ReturnType) -> Option<Self>
+    {
+        let syn::ReturnType::Type(_, output_ty) = output else {return None};
+        let Type::Path(tpath) = output_ty.as_ref() else {return None};
+        let last = tpath.path.segments.last()?;
+        if last.ident == "Option" {Some(Self::Option)}
+        else if last.ident == "Result" {Some(Self::Result)}
+        else if tpath.path.is_ident("bool") {Some(Self::Bool)}
+        else {None}
+    }
+}
+
+/// Calls to the designated member and the links of the chain in order, which stop at the first link that answers
+pub(crate) struct ChainProxy<'a>
+{
+    pub(crate) options:&'a ForwardingOptions
+}
+
+impl ChainProxy<'_>
+{
+    /// Member that receives the calls of a link, which stands for each element of the spread links
+    pub(crate) fn link_component(&self, link:&ChainLink) -> ForwardingMember
+    {
+        if link.elements {BroadcastProxy{options:self.options}.element_member(&link.member)} else {link.member.clone()}
+    }
+
+    /// Checks the answer of a call, breaking out of the chain with it. The trace function is called with the names
+    /// of the method and the link that answered.
+    fn generate_answer_check(&self, outcome:ChainOutcome, link_call:TkStream, method_name:&syn::Ident, link:&ForwardingMember) -> TkStream
+    {
+        let trace_call = self.options.chain_trace.as_ref().map(|chain_trace| {
+            let method_str = method_name.to_string();
+            let link_str = link.member_name();
+            quote::quote!(#chain_trace(#method_str, #link_str);)
+        });
+
+        match outcome {
+            ChainOutcome::Option => quote::quote!(
+                if let ::core::option::Option::Some(__answer) = #link_call {
+                    #trace_call
+                    break '__chain ::core::option::Option::Some(__answer);
+                }
+            ),
+            ChainOutcome::Result => quote::quote!(
+                match #link_call {
+                    ::core::result::Result::Ok(__answer) => {
+                        #trace_call
+                        break '__chain ::core::result::Result::Ok(__answer);
+                    },
+                    ::core::result::Result::Err(__error) => __errors.push(__error)
+                }
+            ),
+            ChainOutcome::Bool => quote::quote!(
+                if #link_call {
+                    #trace_call
+                    break '__chain true;
+                }
+            )
+        }
+    }
+
+    /// Value of the chain when no link answers. Errors are combined following the `chain_error` option,
+    /// which returns the `last` error by default, the `first` one, or the value of a function that receives all of them.
+    fn generate_exhausted(&self, outcome:ChainOutcome, submember:&ForwardingMember) -> TkStream
+    {
+        let empty_message = format!("no link of the chain from the field `{}` returned an error", submember.member_name());
+        match (outcome, &self.options.chain_error) {
+            (ChainOutcome::Option, _) => quote::quote!(::core::option::Option::None),
+            (ChainOutcome::Bool, _) => quote::quote!(false),
+            (ChainOutcome::Result, Some(chain_error)) if chain_error.is_ident(FORWARD_CHAIN_ERROR_FIRST) => quote::quote!(
+                ::core::result::Result::Err(::core::option::Option::expect(__errors.into_iter().next(), #empty_message))
+            ),
+            (ChainOutcome::Result, Some(chain_error)) if !chain_error.is_ident(FORWARD_CHAIN_ERROR_LAST) => quote::quote!(
+                ::core::result::Result::Err(#chain_error(__errors))
+            ),
+            (ChainOutcome::Result, _) => quote::quote!(
+                ::core::result::Result::Err(::core::option::Option::expect(__errors.pop(), #empty_message))
+            )
+        }
+    }
+
+    /// Tries the method on the designated member and the links of the chain in order, inside a labeled block
+    /// that breaks with the first answer. It's `None` for methods whose output doesn't tell if a link answered,
+    /// which are forwarded to the designated member only.
+    pub(crate) fn generate_chain(
+        &self,
+        submember:&ForwardingMember,
+        trait_path:&syn::Path,
+        method_sig:&Signature,
+        signature_parts:&MethodSignatureParts,
+        receiver_kind:ReceiverKind
+    ) -> Result<Option<TkStream>, MethodAdaptError>
+    {
+        let Some(outcome) = ChainOutcome::from_output(&method_sig.output) else {return Ok(None)};
+        let method_name = &method_sig.ident;
+        if !matches!(receiver_kind, ReceiverKind::Ref | ReceiverKind::RefMut | ReceiverKind::Value) {
+            return Err(MethodAdaptError::Unforwardable(method_name.span(), format!(
+                "Method `{}` cannot be chained from the field `{}`, as it requires a `self`, `&self` or `&mut self` receiver.",
+                method_name, submember.member_name()
+            )));
+        }
+
+        let broadcast_proxy = BroadcastProxy{options:self.options};
+        let parameters = broadcast_proxy.generate_parameters(signature_parts);
+        let designated_link = ChainLink{member:submember.clone(), elements:false};
+        let link_checks = std::iter::once(&designated_link).chain(self.options.chain.iter()).map(
+            |link| -> Result<TkStream, MethodAdaptError> {
+                let link_component = self.link_component(link);
+                let component_ty = receiver_kind.generate_component_type(&link_component, self.options);
+                if !link.elements {
+                    let member_access = receiver_kind.generate_member_access(&link.member, self.options, method_name)?;
+                    let link_call = generate_component_call(trait_path, component_ty.as_ref(), member_access, method_sig, &parameters);
+                    return Ok(self.generate_answer_check(outcome, link_call, method_name, &link.member));
+                }
+
+                let elements = broadcast_proxy.generate_elements(&link.member, receiver_kind, method_name)?;
+                let element_arg = broadcast_proxy.generate_element_arg(&link.member, receiver_kind);
+                let element_call = generate_component_call(trait_path, component_ty.as_ref(), element_arg, method_sig, &parameters);
+                let element_check = self.generate_answer_check(outcome, element_call, method_name, &link.member);
+                Ok(quote::quote!(
+                    for __element in #elements {
+                        #element_check
+                    }
+                ))
+            }
+        ).collect::<Result<Vec<TkStream>, _>>()?;
+
+        let errors_decl = if outcome == ChainOutcome::Result {Some(quote::quote!(let mut __errors = ::std::vec::Vec::new();))} else {None};
+        let exhausted = self.generate_exhausted(outcome, submember);
+        Ok(Some(quote::quote!(
+            '__chain: {
+                #errors_decl
+                #(#link_checks)*
+                #exhausted
+            }
+        )))
+    }
+}
@@ -9,13 +9,24 @@ use proc_macro2::token_stream::TokenStream as TkStream;
 use trait_info_gen::TraitPathAttrib as TraitPathAttrib;
 use syn::Ident;
 use quote::ToTokens;
+use syn::spanned::Spanned;
+use crate::method_member_adapter::ForwardingMember;
+use crate::forwarding_options::{ForwardingOptions, FORWARD_FALLBACK_DEFAULT, FORWARD_FALLBACK_PANIC};
+use crate::method_filter::MethodFilter;
+use crate::variant_dispatch::{ForwardingTarget, VariantArm, VariantHandling};
+use crate::swappable_slot::{check_swappable_type, generate_slot_methods};
 use sha3::{Digest, Sha3_256};
 use base32ct::{Base32Unpadded, Encoding};
 
 pub struct ForwardingDeriveMemberTask
 {
-    pub submember_id:Ident,
-    pub trait_path:TraitPathAttrib
+    pub target:ForwardingTarget,
+    pub trait_path:TraitPathAttrib,
+    /// Selection of the forwarded methods, declared as `Trait(except(..))` or `Trait(only(..))`
+    pub filter:MethodFilter,
+    pub options:ForwardingOptions,
+    /// The trait was declared inside `unsafe(..)`, acknowledging that the field upholds its safety contract
+    pub unsafe_impl:bool
 }
 
 pub struct ForwardingDeriveInput
@@ -23,6 +34,8 @@ pub struct ForwardingDeriveInput
     pub type_id:Ident,
     pub generics_info:syn::Generics,
     pub member_tasks:Vec<ForwardingDeriveMemberTask>,
+    /// Fields declared as `swappable`, which receive the setters of their component
+    pub swappable_slots:Vec<ForwardingMember>,
     pub process_macro_path: syn::Path
 }
 
@@ -58,7 +71,13 @@ impl ForwardingDeriveInput
         // hash forwarding task collection
         for task in &self.member_tasks
         {
-            let strtask = format!("member:{},trait{}", task.submember_id.to_string(), task.trait_path);
+            let strtask = format!("member:{},trait{},filter:{},options:{},unsafe:{}",
+                task.target.to_token_stream(),
+                task.trait_path,
+                task.filter.to_token_stream(),
+                task.options.to_token_stream(),
+                task.unsafe_impl
+            );
             hasher.update(strtask.as_bytes());
         }
 
@@ -80,9 +99,12 @@ impl ForwardingDeriveInput
             |derive_task| -> TkStream 
             {
                 let trait_info_macro = derive_task.trait_path.gen_info_macro_path();
-                let trait_path_full = derive_task.trait_path.gen_path();
-                let member_id = &derive_task.submember_id;
-                quote::quote!(#trait_info_macro!(#bridge_macro_name_id,[#member_id{#trait_path_full}]);)
+                let trait_path_full = derive_task.trait_path.gen_full_path();
+                let member_id = &derive_task.target;
+                let filter = &derive_task.filter;
+                let options = &derive_task.options;
+                let unsafe_key:Option<syn::token::Unsafe> = if derive_task.unsafe_impl {Some(Default::default())} else {None};
+                quote::quote!(#trait_info_macro!(#bridge_macro_name_id,[#member_id;traitpath[#unsafe_key #trait_path_full];filter[#filter];options[#options]]);)
             }
         ).collect();
 
@@ -92,18 +114,40 @@ impl ForwardingDeriveInput
         // 4) Receiver macro name
         let receiver_macro = &self.process_macro_path;
 
-        // 5) Compose macro with bridge and invocations        
+        // 5) Setters of the swappable fields, which don't depend on the traits
+        let slot_methods = self.generate_slot_impl();
+
+        // 6) Compose macro with bridge and invocations        
         quote::quote!(
             macro_rules! #bridge_macro_name_id
             {
-                ([$member_id:ident{$trait_path_full:path}],[$($traitinfo:tt)*]) => {
-                    #receiver_macro!(header[#typeheaderblock];$member_id;traitpath[$trait_path_full];traitdef[$($traitinfo)*]);
+                ([$($member_task:tt)*],[$($traitinfo:tt)*]) => {
+                    #receiver_macro!(header[#typeheaderblock];$($member_task)*;traitdef[$($traitinfo)*]);
                 };
             }
 
             #(#invocations)*
+
+            #slot_methods
         )
     }
+
+    /// Inherent implementation with the setters of the swappable fields
+    fn generate_slot_impl(&self) -> Option<TkStream>
+    {
+        if self.swappable_slots.is_empty() {
+            return None;
+        }
+
+        let type_id = &self.type_id;
+        let (impl_generics, type_generics, where_clause) = self.generics_info.split_for_impl();
+        let slot_methods = self.swappable_slots.iter().map(generate_slot_methods);
+        Some(quote::quote!(
+            impl #impl_generics #type_id #type_generics #where_clause {
+                #(#slot_methods)*
+            }
+        ))
+    }
 }
 
 /// Parsing
@@ -132,44 +176,370 @@ impl syn::parse::Parse for ForwardingDeriveInput
         ///////////////
         // check members
         let mut tasks_list: Vec<ForwardingDeriveMemberTask> = Vec::new();
+        let mut swappable_slots: Vec<ForwardingMember> = Vec::new();
         match &structinput.data 
         {
             syn::Data::Struct(strobj) => {
-                strobj.fields.iter().try_for_each(
-                    |f| -> syn::Result<()> 
+                strobj.fields.iter().enumerate().try_for_each(
+                    |(field_index, f)| -> syn::Result<()> 
                     {
-                        if let Some(field_id) =  &f.ident
-                        {
-                            // Attribute parameter should have trait paths
-                            f.attrs.iter().try_for_each(
-                                |attrib| ->syn::Result<()> {
-                                    
-                                    // check attribute if indicates a forward_derive
-                                    if attrib.path().is_ident(FORWARD_DERIVE_MEMBER_ATTRIB) {
-
-                                        attrib.parse_nested_meta(|meta| -> syn::Result<()> {
-                                            let parsed_path = TraitPathAttrib::from_path::<false>(meta.path.clone(), field_id.span())?;
-                                            tasks_list.push(ForwardingDeriveMemberTask{submember_id:field_id.clone(), trait_path:parsed_path});
-                                            Ok(())
-                                        }) // attrib.parse_nested_meta(|meta| -> syn::Result<()> {
-                                    }else { Ok(()) }                                
-                                } // |attrib| ->syn::Result<()> 
-                            ) // f.attrs.iter().try_for_each
-                        } // if let Some(field_id) =  &f.ident
-                        else {
-                            syn::Result::Err(syn::Error::new(structinput.ident.span(), "A named field is required."))                        
-                        }
-                    } // |f| -> syn::Result<()> 
+                        let field_member = generate_field_member(field_index, f);
+                        let field_span = f.ident.as_ref().map_or(f.ty.span(), |field_id| field_id.span());
+
+                        // Attribute parameter should have trait paths
+                        f.attrs.iter().filter(|attrib| attrib.path().is_ident(FORWARD_DERIVE_MEMBER_ATTRIB)).try_for_each(
+                            |attrib| ->syn::Result<()> {
+                                let (trait_entries, mut options) = parse_forward_attribute(attrib, field_span)?;
+
+                                // derived implementations forward to the designated field
+                                if let Some(accessor) = options.first_accessor() {
+                                    return Err(syn::Error::new_spanned(accessor, "Accessors are only supported by `forward_trait`, `forward_derive` forwards to the designated field."));
+                                }
+
+                                // routed members take the declared types of the struct fields
+                                for route in options.route.iter_mut() {
+                                    let routed_field = strobj.fields.iter().enumerate().find(
+                                        |(routed_index, routed)| generate_field_member(*routed_index, routed) == route.member.member
+                                    ).ok_or_else(|| syn::Error::new(route.member.member_span(), format!(
+                                        "Method `{}` is routed to the field `{}`, which isn't declared in the struct.",
+                                        route.method, route.member.member_name()
+                                    )))?;
+                                    route.member.member_ty.get_or_insert_with(|| routed_field.1.ty.clone());
+                                }
+
+                                // and so do the links of the chain
+                                for link in options.chain.iter_mut() {
+                                    let linked_field = strobj.fields.iter().enumerate().find(
+                                        |(linked_index, linked)| generate_field_member(*linked_index, linked) == link.member.member
+                                    ).ok_or_else(|| syn::Error::new(link.member.member_span(), format!(
+                                        "The chain links the field `{}`, which isn't declared in the struct.", link.member.member_name()
+                                    )))?;
+                                    link.member.member_ty.get_or_insert_with(|| linked_field.1.ty.clone());
+                                }
+
+                                // swappable fields receive their setters once, even if several attributes declare them
+                                if let Some(swappable) = &options.swappable {
+                                    check_swappable_type(swappable, &f.ty)?;
+                                    if !swappable_slots.iter().any(|slot| slot.member == field_member) {
+                                        swappable_slots.push(ForwardingMember{member:field_member.clone(), member_ty:Some(f.ty.clone())});
+                                    }
+                                }
+
+                                // options apply to every trait in the attribute
+                                tasks_list.extend(trait_entries.into_iter().map(|trait_entry| ForwardingDeriveMemberTask{
+                                    target:ForwardingTarget::Member(Box::new(ForwardingMember{member:field_member.clone(), member_ty:Some(f.ty.clone())})),
+                                    trait_path:trait_entry.trait_path,
+                                    filter:trait_entry.filter,
+                                    options:options.clone(),
+                                    unsafe_impl:trait_entry.unsafe_impl
+                                }));
+                                Ok(())
+                            } // |attrib| ->syn::Result<()> 
+                        ) // f.attrs.iter().try_for_each
+                    } // |(field_index, f)| -> syn::Result<()> 
                 ) // strobj.fields.iter().try_for_each
             }, // syn::Data::Struct(strobj)
-            _ => {syn::Result::Err(syn::Error::new(structinput.ident.span(), "An Struct Type is required."))}
+            syn::Data::Enum(enumobj) => {
+                tasks_list = parse_enum_tasks(&structinput.attrs, enumobj)?;
+                Ok(())
+            },
+            _ => {syn::Result::Err(syn::Error::new(structinput.ident.span(), "An Struct or Enum Type is required."))}
         }?;
         // create header parametrs
         Ok(Self{
             type_id:structinput.ident,
             generics_info: structinput.generics,
             member_tasks: tasks_list,
+            swappable_slots,
             process_macro_path: macropath
         })
     }
 }
+
+/// Member expression of a field, its name or its tuple index
+fn generate_field_member(field_index:usize, field:&syn::Field) -> syn::Member
+{
+    match &field.ident {
+        Some(field_id) => syn::Member::Named(field_id.clone()),
+        None => syn::Member::Unnamed(syn::Index{index:field_index as u32, span:field.ty.span()})
+    }
+}
+
+/// Trait listed in a `forward_derive` attribute
+struct ForwardTraitEntry
+{
+    trait_path:TraitPathAttrib,
+    /// listed inside `unsafe(..)`
+    unsafe_impl:bool,
+    filter:MethodFilter
+}
+
+/// Parses a trait path with its generic arguments, followed by an optional method filter:
+/// `Trait<Args>(except(..))`. Path segments are parsed one by one, because a regular path
+/// would take the filter as the arguments of a `Fn(..)` trait.
+fn parse_trait_entry(input: syn::parse::ParseStream, span:proc_macro2::Span, unsafe_impl:bool) -> syn::Result<ForwardTraitEntry>
+{
+    let mut trait_path = syn::Path{leading_colon:input.parse()?, segments:syn::punctuated::Punctuated::new()};
+    loop {
+        let ident = input.call(syn::ext::IdentExt::parse_any)?;
+        let arguments = if input.peek(syn::Token![<]) {
+            syn::PathArguments::AngleBracketed(input.parse()?)
+        } else {syn::PathArguments::None};
+        trait_path.segments.push(syn::PathSegment{ident, arguments});
+
+        if !input.peek(syn::Token![::]) {
+            break;
+        }
+        trait_path.segments.push_punct(input.parse()?);
+    }
+
+    let filter = if input.peek(syn::token::Paren) {
+        let filter_content;
+        let _parens = syn::parenthesized!(filter_content in input);
+        filter_content.parse::<MethodFilter>()?
+    } else {MethodFilter::All};
+
+    Ok(ForwardTraitEntry{trait_path:TraitPathAttrib::from_path::<true>(trait_path, span)?, unsafe_impl, filter})
+}
+
+/// Checks if the next entry of the attribute is the `map(..)` or `route(..)` option, instead of a filtered trait.
+fn is_list_option_entry(input: syn::parse::ParseStream) -> bool
+{
+    input.peek2(syn::token::Paren) && input.fork().parse::<Ident>().is_ok_and(|key| ForwardingOptions::is_list_option_key(&key))
+}
+
+/// Checks if the next entry of the attribute is a flag option like `broadcast`, instead of a trait.
+fn is_flag_option_entry(input: syn::parse::ParseStream) -> bool
+{
+    let fork = input.fork();
+    fork.parse::<Ident>().is_ok_and(|key| ForwardingOptions::is_flag_option_key(&key)) && (fork.is_empty() || fork.peek(syn::Token![,]))
+}
+
+/// Parses a `forward_derive` attribute, with the list of traits and the forwarding options.
+/// Traits listed inside `unsafe(..)` are flagged as acknowledged unsafe implementations.
+/// 
+/// Trait paths can have generic arguments (`Parser<'a, Token>`) and method filters, so the attribute is parsed as a
+/// comma separated list instead of nested meta items, which only accept module-style paths.
+fn parse_forward_attribute(
+    attrib:&syn::Attribute,
+    span:proc_macro2::Span
+) -> syn::Result<(Vec<ForwardTraitEntry>, ForwardingOptions)>
+{
+    let mut trait_entries: Vec<ForwardTraitEntry> = Vec::new();
+    let mut options = ForwardingOptions::default();
+
+    attrib.parse_args_with(|input: syn::parse::ParseStream| -> syn::Result<()> {
+        while !input.is_empty() {
+            if input.peek(syn::Token![unsafe]) {
+                // unsafe traits are listed inside `unsafe(..)`
+                let _unsafe_key = input.parse::<syn::Token![unsafe]>()?;
+                let unsafe_content;
+                let _parens = syn::parenthesized!(unsafe_content in input);
+                while !unsafe_content.is_empty() {
+                    trait_entries.push(parse_trait_entry(&unsafe_content, span, true)?);
+                    if !unsafe_content.is_empty() {
+                        let _comma = unsafe_content.parse::<syn::Token![,]>()?;
+                    }
+                }
+            }
+            else if input.peek(Ident) && (input.peek2(syn::Token![=]) || is_list_option_entry(input) || is_flag_option_entry(input)) {
+                // options are declared as `key = value`, `map(..)` and `route(..)`, or flags like `broadcast`
+                let option_key:Ident = input.parse()?;
+                if !ForwardingOptions::is_option_key(&option_key) {
+                    return Err(syn::Error::new(option_key.span(), format!("Unknown forwarding option `{}`.", option_key)));
+                }
+                options.parse_option_entry(&option_key, input)?;
+            }
+            else {
+                trait_entries.push(parse_trait_entry(input, span, false)?);
+            }
+
+            if !input.is_empty() {
+                let _comma = input.parse::<syn::Token![,]>()?;
+            }
+        }
+        Ok(())
+    })?;
+
+    Ok((trait_entries, options))
+}
+
+/// Trait dispatched on the variants of an enum, with the handling of each variant
+struct EnumTraitDispatch
+{
+    trait_path:TraitPathAttrib,
+    unsafe_impl:bool,
+    filter:MethodFilter,
+    /// fallback declared on the enum attribute, for the variants without a single field
+    fallback:Option<syn::Path>,
+    handlings:Vec<Option<VariantHandling>>
+}
+
+/// Collects the traits declared with `forward_derive` at the enum, variant and field levels,
+/// and resolves how every variant dispatches each trait:
+/// * A field designated with `#[forward_derive(Trait)]` receives the methods.
+/// * A variant declared with `#[forward_derive(Trait)]` forwards to its only field,
+///   or follows its fallback when it's declared as `#[forward_derive(Trait, fallback = panic)]`.
+/// * Otherwise, variants with a single field forward to it, and the rest follow the fallback declared on the enum.
+fn parse_enum_tasks(enum_attrs:&[syn::Attribute], enumobj:&syn::DataEnum) -> syn::Result<Vec<ForwardingDeriveMemberTask>>
+{
+    let variants_count = enumobj.variants.len();
+    let mut dispatches:Vec<EnumTraitDispatch> = Vec::new();
+
+    // registers the trait, and returns its index in the dispatch list
+    let mut register_trait = |trait_entry:ForwardTraitEntry| -> syn::Result<usize> {
+        let trait_key = trait_entry.trait_path.to_string();
+        let index = match dispatches.iter().position(|dispatch| dispatch.trait_path.to_string() == trait_key) {
+            Some(index) => index,
+            None => {
+                dispatches.push(EnumTraitDispatch{
+                    trait_path:trait_entry.trait_path,
+                    unsafe_impl:false,
+                    filter:MethodFilter::All,
+                    fallback:None,
+                    handlings:vec![None; variants_count]
+                });
+                dispatches.len() - 1
+            }
+        };
+
+        let dispatch = &mut dispatches[index];
+        dispatch.unsafe_impl |= trait_entry.unsafe_impl;
+        if !matches!(trait_entry.filter, MethodFilter::All) {
+            if !matches!(dispatch.filter, MethodFilter::All) {
+                return Err(syn::Error::new(dispatch.trait_path.trait_name.span(), format!(
+                    "The methods of trait `{}` are filtered more than once.", dispatch.trait_path.trait_name
+                )));
+            }
+            dispatch.filter = trait_entry.filter;
+        }
+        Ok(index)
+    };
+
+    // only the fallback option is supported on enums
+    let check_options = |options:&ForwardingOptions, allow_fallback:bool, span:proc_macro2::Span| -> syn::Result<()> {
+        let unsupported = options.rc_project.is_some() || options.arc_project.is_some() ||
+            options.construct.is_some() || options.rewrap.is_some() || !options.map.is_empty() || !options.route.is_empty() || options.has_accessors() ||
+            options.cell.is_some() || options.on_error.is_some() || options.optional.is_some() || !options.method_fallback.is_empty() || options.lazy.is_some() ||
+            options.broadcast.is_some() || !options.aggregate.is_empty() || !options.chain.is_empty() ||
+            options.chain_error.is_some() || options.chain_trace.is_some() || options.swappable.is_some() ||
+            (!allow_fallback && options.fallback.is_some());
+        if unsupported {
+            let message = if allow_fallback {"Only the `fallback` option is supported on enums."}
+                else {"Only the `fallback` option is supported on enums, and it has to be declared on the enum or on its variants."};
+            return Err(syn::Error::new(span, message));
+        }
+
+        if let Some(fallback) = options.fallback.as_ref().filter(
+            |fallback| !fallback.is_ident(FORWARD_FALLBACK_DEFAULT) && !fallback.is_ident(FORWARD_FALLBACK_PANIC)
+        ) {
+            return Err(syn::Error::new_spanned(fallback, "Expected `fallback = default` or `fallback = panic`."));
+        }
+        Ok(())
+    };
+
+    let mut dispatch_fallbacks:Vec<(usize, syn::Path)> = Vec::new();
+    let mut variant_handlings:Vec<(usize, usize, VariantHandling, proc_macro2::Span)> = Vec::new();
+
+    // enum level
+    for attrib in enum_attrs.iter().filter(|attrib| attrib.path().is_ident(FORWARD_DERIVE_MEMBER_ATTRIB)) {
+        let (trait_entries, options) = parse_forward_attribute(attrib, attrib.span())?;
+        check_options(&options, true, attrib.span())?;
+        for trait_entry in trait_entries {
+            let dispatch_index = register_trait(trait_entry)?;
+            if let Some(fallback) = &options.fallback {
+                dispatch_fallbacks.push((dispatch_index, fallback.clone()));
+            }
+        }
+    }
+
+    for (variant_index, variant) in enumobj.variants.iter().enumerate() {
+        let single_field = variant.fields.len() == 1;
+
+        // variant level
+        for attrib in variant.attrs.iter().filter(|attrib| attrib.path().is_ident(FORWARD_DERIVE_MEMBER_ATTRIB)) {
+            let (trait_entries, options) = parse_forward_attribute(attrib, variant.ident.span())?;
+            check_options(&options, true, attrib.span())?;
+            for trait_entry in trait_entries {
+                let dispatch_index = register_trait(trait_entry)?;
+                let handling = match (&options.fallback, variant.fields.iter().next()) {
+                    (Some(fallback), _) => VariantHandling::Fallback(fallback.clone()),
+                    (None, Some(field)) if single_field => VariantHandling::Forward{
+                        field:Box::new(ForwardingMember{member:generate_field_member(0, field), member_ty:Some(field.ty.clone())}),
+                        single:true
+                    },
+                    _ => {
+                        return Err(syn::Error::new(variant.ident.span(), format!(
+                            "Variant `{}` doesn't have a single field, so a field has to be designated with `#[forward_derive(..)]`, or a `fallback` has to be declared.",
+                            variant.ident
+                        )));
+                    }
+                };
+                variant_handlings.push((dispatch_index, variant_index, handling, variant.ident.span()));
+            }
+        }
+
+        // field level
+        for (field_index, field) in variant.fields.iter().enumerate() {
+            let field_span = field.ident.as_ref().map_or(field.ty.span(), |field_id| field_id.span());
+            for attrib in field.attrs.iter().filter(|attrib| attrib.path().is_ident(FORWARD_DERIVE_MEMBER_ATTRIB)) {
+                let (trait_entries, options) = parse_forward_attribute(attrib, field_span)?;
+                check_options(&options, false, attrib.span())?;
+                for trait_entry in trait_entries {
+                    let dispatch_index = register_trait(trait_entry)?;
+                    let handling = VariantHandling::Forward{
+                        field:Box::new(ForwardingMember{member:generate_field_member(field_index, field), member_ty:Some(field.ty.clone())}),
+                        single:single_field
+                    };
+                    variant_handlings.push((dispatch_index, variant_index, handling, field_span));
+                }
+            }
+        }
+    }
+
+    for (dispatch_index, fallback) in dispatch_fallbacks {
+        dispatches[dispatch_index].fallback = Some(fallback);
+    }
+
+    for (dispatch_index, variant_index, handling, span) in variant_handlings {
+        let dispatch = &mut dispatches[dispatch_index];
+        if dispatch.handlings[variant_index].is_some() {
+            return Err(syn::Error::new(span, format!(
+                "Trait `{}` is declared more than once for the variant `{}`.",
+                dispatch.trait_path.trait_name, enumobj.variants[variant_index].ident
+            )));
+        }
+        dispatch.handlings[variant_index] = Some(handling);
+    }
+
+    // resolve the variants without explicit handling
+    dispatches.into_iter().map(|dispatch| -> syn::Result<ForwardingDeriveMemberTask> {
+        let arms = enumobj.variants.iter().zip(dispatch.handlings).map(
+            |(variant, handling)| -> syn::Result<VariantArm> {
+                let handling = match (handling, &dispatch.fallback, variant.fields.iter().next()) {
+                    (Some(handling), _, _) => handling,
+                    (None, _, Some(field)) if variant.fields.len() == 1 => VariantHandling::Forward{
+                        field:Box::new(ForwardingMember{member:generate_field_member(0, field), member_ty:Some(field.ty.clone())}),
+                        single:true
+                    },
+                    (None, Some(fallback), _) => VariantHandling::Fallback(fallback.clone()),
+                    (None, None, _) => {
+                        return Err(syn::Error::new(variant.ident.span(), format!(
+                            "Variant `{}` cannot forward `{}`, because it doesn't have a single field. Designate one of its fields with `#[forward_derive({})]`, or declare a fallback with `#[forward_derive({}, fallback = default | panic)]` on the variant or on the enum.",
+                            variant.ident, dispatch.trait_path.trait_name, dispatch.trait_path.trait_name, dispatch.trait_path.trait_name
+                        )));
+                    }
+                };
+                Ok(VariantArm{variant:variant.ident.clone(), handling})
+            }
+        ).collect::<syn::Result<Vec<VariantArm>>>()?;
+
+        Ok(ForwardingDeriveMemberTask{
+            target:ForwardingTarget::Variants(arms),
+            trait_path:dispatch.trait_path,
+            filter:dispatch.filter,
+            options:ForwardingOptions::default(),
+            unsafe_impl:dispatch.unsafe_impl
+        })
+    }).collect()
+}
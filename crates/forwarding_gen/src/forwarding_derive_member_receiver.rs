@@ -7,7 +7,13 @@ License Apache-2.0
 use proc_macro2::token_stream::TokenStream as TkStream;
 use syn::{Ident,Path};
 
-use crate::method_member_adapter::TraitMethodImplMacros;
+use crate::method_member_adapter::{ForwardingMember, TraitMethodImplMacros, MethodForwardingContext, MethodAdaptError, ReceiverKind, generate_unforwardable_error};
+use crate::variant_dispatch::{ForwardingTarget, VariantDispatchContext, VariantHandling};
+use crate::forwarding_options::ForwardingOptions;
+use crate::method_filter::MethodFilter;
+use crate::partial_impl::partial_impl_macro_name;
+use crate::trait_args_mapping::{TraitArgsMapping, undeclared_lifetimes, type_mentions_params};
+use syn::fold::Fold;
 
 struct GenericParamsIntancing
 {
@@ -74,8 +80,11 @@ pub struct ForwardingDeriveMemberReceiver
 {
     pub type_id:Ident,
     pub generics_info:syn::Generics,
-    pub submember_id:Ident,
+    pub target:ForwardingTarget,
     pub trait_path:Path,
+    pub unsafe_impl:bool,
+    pub filter:MethodFilter,
+    pub options:ForwardingOptions,
     pub trait_info_obj:trait_info_gen::SimpleTraitInfo
 }
 
@@ -84,55 +93,236 @@ impl ForwardingDeriveMemberReceiver
     pub fn generate_impl_methods(&self) -> syn::Result<TkStream>
     {
         let typeid = &self.type_id;
-        if self.trait_info_obj.generics.params.len() > 0 {
-            return syn::Result::Err(syn::Error::new(typeid.span(), "Cannot implement a trait with generic arguments."));
+        let trait_path = &self.trait_path;
+
+        // generic parameters of the trait are substituted by the arguments of the trait path
+        let trait_args = trait_path.segments.last().map_or(syn::PathArguments::None, |segment| segment.arguments.clone());
+        let mut args_mapping = TraitArgsMapping::create(
+            &self.trait_info_obj.ident, &self.trait_info_obj.generics, &trait_args, typeid.span()
+        )?;
+
+        // unsafe traits require the explicit acknowledgment of the derive attribute
+        if self.trait_info_obj.unsafety != self.unsafe_impl {
+            let trait_name = &self.trait_info_obj.ident;
+            let message = if self.trait_info_obj.unsafety {
+                format!("Trait `{}` is unsafe, so forwarding it to {} requires acknowledging its safety contract with `#[forward_derive(unsafe({}))]`.",
+                    trait_name, self.target.describe(), trait_name)
+            } else {
+                format!("Trait `{}` is not unsafe, so it has to be listed outside of `unsafe(..)`.", trait_name)
+            };
+            return syn::Result::Err(syn::Error::new(typeid.span(), message));
         }
 
-        let trait_path = &self.trait_path;
+        let unsafe_key:Option<syn::token::Unsafe> = if self.unsafe_impl {Some(Default::default())} else {None};
+
+        // lifetimes of the trait arguments that the type doesn't declare are declared by the implementation
+        let trait_lifetimes = undeclared_lifetimes(&trait_args, &self.generics_info);
 
-        let impl_trait_header_decl: TkStream = if self.generics_info.params.len() > 0 {
+        let impl_trait_header_decl: TkStream = if self.generics_info.params.len() + trait_lifetimes.len() > 0 {
     
             let generic_params_decl = &self.generics_info.params;
+            let lifetimes_comma:Option<syn::token::Comma> = if trait_lifetimes.len() > 0 && generic_params_decl.len() > 0 {
+                Some(syn::token::Comma::default())
+            } else {None};
             // declare with generic arguments
             let generic_params_inst = GenericParamsIntancing::create(&self.generics_info).generate_tokens();
     
-            let wherecause_tks = &self.generics_info.where_clause;
+            let wherecause_tks = self.generate_where_clause();
                             
             quote::quote!(
-                impl< #generic_params_decl > #trait_path for #typeid #generic_params_inst #wherecause_tks
+                #unsafe_key impl< #(#trait_lifetimes),* #lifetimes_comma #generic_params_decl > #trait_path for #typeid #generic_params_inst #wherecause_tks
             )
         }
         else {
             quote::quote!(
-                impl #trait_path for #typeid
+                #unsafe_key impl #trait_path for #typeid
             )
         };
 
         let base_hash = impl_trait_header_decl.to_string();
     
-        // generate methods
-        let impl_method_pairs:Vec<TraitMethodImplMacros> = self.trait_info_obj.functions.iter().map(
-            |fsig| TraitMethodImplMacros::create(
-                &self.submember_id, fsig, &base_hash
-            ).or_else(|err| Err(err.into()) )
-        ).collect::<syn::Result<_> >()?;
+        // structs forward to a member, and enums dispatch on their variants
+        let create_method = |fsig:&syn::Signature| -> Result<TraitMethodImplMacros, MethodAdaptError> {
+            match &self.target {
+                ForwardingTarget::Member(submember) => {
+                    let forwarding_context = MethodForwardingContext{
+                        submember,
+                        trait_path,
+                        options:&self.options
+                    };
+                    TraitMethodImplMacros::create(&forwarding_context, fsig, &base_hash)
+                },
+                ForwardingTarget::Variants(arms) => {
+                    VariantDispatchContext{arms, trait_path}.create_method(fsig, &base_hash)
+                }
+            }
+        };
+    
+        // methods excluded by the filter are implemented by inherent methods of the type, or by its partial implementation
+        self.filter.validate(&self.trait_info_obj)?;
+        let excluded_methods = self.filter.excluded_methods(&self.trait_info_obj);
+        let forwarded_indices = self.trait_info_obj.filter_functions(&excluded_methods);
+
+        self.options.validate_methods(&self.trait_info_obj, &excluded_methods)?;
+
+        // generate methods, every method that cannot be forwarded is reported in a single diagnostic
+        let mut impl_method_pairs:Vec<TraitMethodImplMacros> = Vec::new();
+        let mut failures:Vec<(Ident, MethodAdaptError)> = Vec::new();
+        self.trait_info_obj.functions.iter().enumerate().for_each(|(index, fsig)| {
+            let fsig = args_mapping.fold_signature(fsig.clone());
+            let method_pair = match (forwarded_indices.contains(&index), self.filter.inherent_method(&fsig.ident)) {
+                (true, _) => create_method(&fsig),
+                (false, Some(inherent)) => TraitMethodImplMacros::create_inherent_call(&fsig, inherent, trait_path, &base_hash),
+                (false, None) => return
+            };
+
+            match method_pair {
+                Ok(pair) => { impl_method_pairs.push(pair); },
+                Err(err) => { failures.push((fsig.ident.clone(), err)); }
+            }
+        });
+
+        if !failures.is_empty() {
+            return syn::Result::Err(generate_unforwardable_error(
+                typeid.span(), &self.trait_info_obj.ident, &self.target.describe(), failures
+            ));
+        }
+
+        let associated_items = self.generate_associated_items(&mut args_mapping)?;
 
         // separate streams
         let (impl_method_decls, impl_method_invoks):
         (Vec<TkStream>,Vec<TkStream>) = impl_method_pairs.into_iter().map(
             |pair| (pair.macro_decl, pair.macro_invoke)
         ).unzip();
-    
+
+        // the partial implementation declares the implementation, and invokes the forwarded items
+        if self.filter.requires_partial_impl(&self.trait_info_obj) {
+            let macro_name = partial_impl_macro_name(typeid, &self.trait_info_obj.ident);
+            return Ok(quote::quote!(
+                #(#impl_method_decls)*
+
+                macro_rules! #macro_name {
+                    () => {
+                        #associated_items
+                        #(#impl_method_invoks)*
+                    };
+                }
+            ));
+        }
+
         let out_tokens = quote::quote!(
             #(#impl_method_decls)*
 
             #impl_trait_header_decl
             {
+                #associated_items
                 #(#impl_method_invoks)*
             }
         );
         Ok(out_tokens)        
     }
+
+    /// Where clause of the implementation, extended with the bound `FieldTy: Trait` for every field type
+    /// that depends on the generic parameters of the type, so generic wrappers don't need declaring it.
+    fn generate_where_clause(&self) -> Option<syn::WhereClause>
+    {
+        let type_params:Vec<Ident> = self.generics_info.type_params().map(|type_param| type_param.ident.clone()).collect();
+        fn field_type(field:&ForwardingMember) -> Option<&syn::Type> {
+            ReceiverKind::Ref.peeled_member_type(field).or(field.member_ty.as_ref())
+        }
+
+        // the elements of the spread links of the chain receive the calls
+        let linked_members:Vec<ForwardingMember> = self.options.chain_proxy().map_or_else(Vec::new,
+            |chain_proxy| self.options.chain.iter().map(|link| chain_proxy.link_component(link)).collect()
+        );
+
+        // the trait is implemented by the type that receives the calls, after peeling references and pointers
+        let field_types:Vec<&syn::Type> = match &self.target {
+            ForwardingTarget::Member(submember) => {
+                // components in optional members or cells are bounded by the type they wrap
+                let submember_ty = submember.member_ty.as_ref().and_then(
+                    |member_ty| self.options.proxied_component_type(member_ty)
+                ).or_else(|| field_type(submember));
+                // the links of the chain implement the trait as well, routed members only implement the routed methods
+                submember_ty.into_iter().chain(linked_members.iter().filter_map(field_type)).collect()
+            },
+            ForwardingTarget::Variants(arms) => arms.iter().filter_map(|arm| match &arm.handling {
+                VariantHandling::Forward{field, ..} => field_type(field),
+                VariantHandling::Fallback(_) => None
+            }).collect()
+        };
+
+        let mut bounded_types:Vec<&syn::Type> = Vec::new();
+        field_types.into_iter().filter(|field_ty| type_mentions_params(field_ty, &type_params)).for_each(|field_ty| {
+            if !bounded_types.contains(&field_ty) {
+                bounded_types.push(field_ty);
+            }
+        });
+
+        if bounded_types.is_empty() {
+            return self.generics_info.where_clause.clone();
+        }
+
+        let trait_path = &self.trait_path;
+        let mut where_clause = self.generics_info.where_clause.clone().unwrap_or_else(|| syn::WhereClause{
+            where_token:Default::default(),
+            predicates:Default::default()
+        });
+
+        where_clause.predicates.extend(bounded_types.into_iter().map(
+            |field_ty| -> syn::WherePredicate { syn::parse_quote!(#field_ty: #trait_path) }
+        ));
+        Some(where_clause)
+    }
+
+    /// Declares the associated types and constants of the trait with the ones of the component type,
+    /// as `type Item = <ComponentTy as Trait>::Item;`.
+    /// Enums take them from the first variant that forwards the trait.
+    fn generate_associated_items(&self, args_mapping:&mut TraitArgsMapping) -> syn::Result<TkStream>
+    {
+        let trait_info = &self.trait_info_obj;
+        if trait_info.typedefs.is_empty() && trait_info.constants.is_empty() {
+            return Ok(quote::quote!());
+        }
+
+        let component_ty = match &self.target {
+            // components in optional members or cells are declared by the type they wrap
+            ForwardingTarget::Member(submember) => match submember.member_ty.as_ref().and_then(|member_ty| self.options.proxied_component_type(member_ty)) {
+                Some(proxied_component_ty) => Some(quote::quote!(#proxied_component_ty)),
+                None => ReceiverKind::Ref.generate_component_type(submember, &self.options)
+            },
+            ForwardingTarget::Variants(arms) => arms.iter().find_map(|arm| match &arm.handling {
+                VariantHandling::Forward{field, ..} => ReceiverKind::Ref.generate_component_type(field, &ForwardingOptions::default()),
+                VariantHandling::Fallback(_) => None
+            })
+        }.ok_or_else(|| syn::Error::new(self.type_id.span(), format!(
+            "Trait `{}` has associated items, so at least one variant has to forward it to a field.", trait_info.ident
+        )))?;
+
+        let trait_path = &self.trait_path;
+        let typedefs = trait_info.typedefs.iter().map(
+            |type_id| quote::quote!(type #type_id = <#component_ty as #trait_path>::#type_id;)
+        );
+
+        let constants = trait_info.constants.iter().map(|constant| {
+            let const_id = &constant.ident;
+            match &constant.ty {
+                Some(const_ty) => {
+                    let const_ty = args_mapping.fold_type(const_ty.clone());
+                    Ok(quote::quote!(const #const_id: #const_ty = <#component_ty as #trait_path>::#const_id;))
+                },
+                None => Err(syn::Error::new(const_id.span(), format!(
+                    "The type of the constant `{}` is required for forwarding it.", const_id
+                )))
+            }
+        }).collect::<syn::Result<Vec<TkStream>>>()?;
+
+        Ok(quote::quote!(
+            #(#typedefs)*
+            #(#constants)*
+        ))
+    }
 }
 
 
@@ -141,8 +331,12 @@ impl syn::parse::Parse for ForwardingDeriveMemberReceiver
     /// Receives a macro syntax that contains the type struct declaration, the submember identifier and the trait definition.
     /// Parameters are separated by semi token `;`, where:
     /// * The first parameter is the declaration type header with generics (delimited by `header[]` block).
-    /// * The second is the identifier of the submember that implements the trait methods.
-    /// * The third is a trait information obtained by reflection macro before (delimited by `traitdef[]` block).
+    /// * The second is the identifier of the submember that implements the trait methods, followed by its type.
+    ///   Enums declare the variants that dispatch the methods instead (delimited by `variants[]` block).
+    /// * The third is the path of the implemented trait (delimited by `traitpath[]` block), preceded by `unsafe` for unsafe traits.
+    /// * Optionally, the selection of the forwarded methods (delimited by `filter[]` block), as `except(..)` or `only(..)`.
+    /// * The fourth is the list of forwarding options (delimited by `options[]` block).
+    /// * The fifth is a trait information obtained by reflection macro before (delimited by `traitdef[]` block).
     /// 
     /// 
     /// ```
@@ -150,8 +344,10 @@ impl syn::parse::Parse for ForwardingDeriveMemberReceiver
     /// header[
     ///     MyStruct<'b,B:bound, C> where C:'b + bound
     /// ];
-    /// submember_id;
-    /// traitpath[CashFlow];
+    /// submember_id: SubmemberType;
+    /// traitpath[unsafe CashFlow];
+    /// filter[except(loan_shark = custom_loan)];
+    /// options[arc_project = project_fn];
     /// traitdef[
     ///      unsafe CashFlow<'ar, Client:Sized> {
     ///         FUNCS[
@@ -169,6 +365,8 @@ impl syn::parse::Parse for ForwardingDeriveMemberReceiver
         {
             syn::custom_keyword!(header);
             syn::custom_keyword!(traitpath);
+            syn::custom_keyword!(filter);
+            syn::custom_keyword!(options);
             syn::custom_keyword!(traitdef);
         }
         
@@ -179,8 +377,8 @@ impl syn::parse::Parse for ForwardingDeriveMemberReceiver
 
         let _sep0 = input.parse::<syn::token::Semi>()?; //;
 
-        // sub member identifier
-        let submember_id:Ident = input.parse()?;
+        // sub member identifier, or the variants of an enum
+        let target:ForwardingTarget = input.parse()?;
         let _sep1 = input.parse::<syn::token::Semi>()?; //;
 
         // traitpath[]
@@ -188,6 +386,22 @@ impl syn::parse::Parse for ForwardingDeriveMemberReceiver
         let trait_path_content;
         let _trait_path_brakets = syn::bracketed!(trait_path_content in input);
         let _sep2 = input.parse::<syn::token::Semi>()?; //;
+
+        // filter[], optional
+        let filter = if input.peek(kw_inner::filter) {
+            let _filtertoken = input.parse::<kw_inner::filter>()?;
+            let filter_content;
+            let _filter_brakets = syn::bracketed!(filter_content in input);
+            let _sep = input.parse::<syn::token::Semi>()?; //;
+            filter_content.parse::<MethodFilter>()?
+        } else {MethodFilter::All};
+
+        // options[]
+        let _optionstoken = input.parse::<kw_inner::options>()?;
+        let options_content;
+        let _options_brakets = syn::bracketed!(options_content in input);
+        let options = options_content.parse::<ForwardingOptions>()?;
+        let _sep3 = input.parse::<syn::token::Semi>()?; //;
         
         // traitdef[]
         let _traitdeftoken = input.parse::<kw_inner::traitdef>()?;
@@ -202,11 +416,12 @@ impl syn::parse::Parse for ForwardingDeriveMemberReceiver
         let genericsfinal = syn::Generics{where_clause:where0, ..generics0};
 
         // process the trait path
+        let unsafe_key:Option<syn::token::Unsafe> = trait_path_content.parse()?;
         let trait_path:Path = trait_path_content.parse()?;
 
         // process the trait info
         let trait_info_obj = trait_def_content.parse::<trait_info_gen::SimpleTraitInfo>()?;
 
-        Ok(Self{type_id:typeid, generics_info:genericsfinal, submember_id, trait_path, trait_info_obj})
+        Ok(Self{type_id:typeid, generics_info:genericsfinal, target, trait_path, unsafe_impl:unsafe_key.is_some(), filter, options, trait_info_obj})
     }
 }
\ No newline at end of file
@@ -0,0 +1,602 @@
+/*
+Hereditary
+Autors: Francisco Leon <https://github.com/superoptimo>
+License Apache-2.0
+*/
+
+use proc_macro2::token_stream::TokenStream as TkStream;
+use quote::ToTokens;
+use syn::Ident;
+
+use crate::method_member_adapter::ReceiverKind;
+use crate::cell_proxy::{CellKind, CellProxy, FORWARD_ON_ERROR_PANIC, peel_cell_type};
+use crate::method_mapping::{MethodMapping, MethodRoute, MethodFallback, MethodAggregate, parse_mappings, parse_routes, parse_method_fallbacks, parse_method_aggregates};
+use crate::optional_member::{OptionalKind, OptionalProxy, peel_optional_type};
+use crate::lazy_member::{LazyProxy, FORWARD_LAZY_FORCE, peel_lazy_type};
+use crate::broadcast_member::{BroadcastProxy, collection_parts};
+use crate::chain_member::{ChainLink, ChainProxy, parse_chain};
+use crate::swappable_slot::peel_slot_type;
+use crate::method_member_adapter::smart_pointer_parts;
+
+pub const FORWARD_OPTION_RC_PROJECT: &str = "rc_project";
+pub const FORWARD_OPTION_ARC_PROJECT: &str = "arc_project";
+pub const FORWARD_OPTION_CONSTRUCT: &str = "construct";
+/// `construct = default` fills the remaining fields of the composite with `Default`
+pub const FORWARD_CONSTRUCT_DEFAULT: &str = "default";
+pub const FORWARD_OPTION_REWRAP: &str = "rewrap";
+/// `rewrap = clone` copies the remaining fields of the composite from the receiver
+pub const FORWARD_REWRAP_CLONE: &str = "clone";
+pub const FORWARD_OPTION_FALLBACK: &str = "fallback";
+/// `fallback = default` returns `Default::default()` from the methods that cannot be forwarded
+pub const FORWARD_FALLBACK_DEFAULT: &str = "default";
+/// `fallback = panic` panics in the methods that cannot be forwarded
+pub const FORWARD_FALLBACK_PANIC: &str = "panic";
+/// `fallback = trait_default` is rejected, as `trait_info` doesn't record the default bodies of the trait methods
+pub const FORWARD_FALLBACK_TRAIT_DEFAULT: &str = "trait_default";
+/// `map(method = component_method, ...)` forwards trait methods to differently named methods of the component
+pub const FORWARD_OPTION_MAP: &str = "map";
+/// `route(method = member, ...)` forwards trait methods to other members of the composite
+pub const FORWARD_OPTION_ROUTE: &str = "route";
+pub const FORWARD_OPTION_VIA: &str = "via";
+pub const FORWARD_OPTION_VIA_REF: &str = "via_ref";
+pub const FORWARD_OPTION_VIA_MUT: &str = "via_mut";
+pub const FORWARD_OPTION_CELL: &str = "cell";
+pub const FORWARD_OPTION_ON_ERROR: &str = "on_error";
+pub const FORWARD_OPTION_OPTIONAL: &str = "optional";
+/// `method_fallback(method = fallback, ...)` overrides the fallback of some methods
+pub const FORWARD_OPTION_METHOD_FALLBACK: &str = "method_fallback";
+/// `lazy = init_fn` builds the component of the submember on first use
+pub const FORWARD_OPTION_LAZY: &str = "lazy";
+/// `broadcast` forwards the trait methods to every element of the submember collection
+pub const FORWARD_OPTION_BROADCAST: &str = "broadcast";
+/// `aggregate(method = aggregator, ...)` combines the values returned by the elements of a broadcast
+pub const FORWARD_OPTION_AGGREGATE: &str = "aggregate";
+/// `chain(member, ...)` tries the methods on the designated member and then on other members, until one of them answers
+pub const FORWARD_OPTION_CHAIN: &str = "chain";
+pub const FORWARD_OPTION_CHAIN_ERROR: &str = "chain_error";
+pub const FORWARD_OPTION_CHAIN_TRACE: &str = "chain_trace";
+/// `swappable` generates the setters that swap the component of the field at runtime
+pub const FORWARD_OPTION_SWAPPABLE: &str = "swappable";
+
+/// Type of a component stored behind a reference or a `Box`, `Rc` or `Arc`, or the type itself otherwise
+fn peel_pointer_type(component_ty:&syn::Type) -> &syn::Type
+{
+    match (component_ty, smart_pointer_parts(component_ty)) {
+        (syn::Type::Reference(tref), _) => tref.elem.as_ref(),
+        (_, Some((pointer_name, pointee))) if pointer_name == "Box" || pointer_name == "Rc" || pointer_name == "Arc" => pointee,
+        _ => component_ty
+    }
+}
+
+/// Optional settings that customize how trait methods are forwarded to the submember.
+///
+/// They are declared as `key = value` entries in the forwarding attributes (or `map(..)` and `route(..)` for the per-method settings), and they are
+/// transported to the receiver macros in an `options[]` block with the same syntax.
+#[derive(Default, Clone)]
+pub struct ForwardingOptions
+{
+    /// Function that projects a `Rc<Self>` receiver into the `Rc` of the submember
+    pub rc_project:Option<syn::Path>,
+    /// Function that projects an `Arc<Self>` receiver into the `Arc` of the submember
+    pub arc_project:Option<syn::Path>,
+    /// Strategy for building the composite from the value returned by associated functions.
+    /// It's either `default` or the path of a function that receives the submember value.
+    pub construct:Option<syn::Path>,
+    /// Strategy for rebuilding the composite from the value returned by methods with receiver.
+    /// It's either `clone` or the path of a function `fn(&Self, Component) -> Self`.
+    pub rewrap:Option<syn::Path>,
+    /// Behavior of the methods when there isn't a component to forward to, like enum variants
+    /// that don't implement the trait, or optional members that are missing. It's either `default` or `panic`,
+    /// or the path of a function for optional members.
+    pub fallback:Option<syn::Path>,
+    /// Trait methods that call a differently named method of the submember
+    pub map:Vec<MethodMapping>,
+    /// Trait methods that are forwarded to other members of the composite
+    pub route:Vec<MethodRoute>,
+    /// Expression that reaches the component from the composite, like `self.parts.wing` or `self.inner()`,
+    /// used by the methods without a specific accessor
+    pub via:Option<syn::Expr>,
+    /// Accessor of the component for `&self` methods, like `get()`
+    pub via_ref:Option<syn::Expr>,
+    /// Accessor of the component for `&mut self` methods, like `get_mut()`
+    pub via_mut:Option<syn::Expr>,
+    /// Cell that guards the submember with interior mutability. It's either `refcell`, `mutex` or `rwlock`.
+    pub cell:Option<syn::Path>,
+    /// Policy when the cell cannot be borrowed or its lock is poisoned. It's either `panic`, or the path
+    /// of a function that receives the error and returns the output of the method.
+    pub on_error:Option<syn::Path>,
+    /// Submember whose component can be missing, which is either an `option` or a `weak` reference
+    pub optional:Option<syn::Path>,
+    /// Trait methods of optional members with their own fallback
+    pub method_fallback:Vec<MethodFallback>,
+    /// Function that builds the component of a `OnceCell` or `OnceLock` submember on first use,
+    /// or `force` for `LazyCell` and `LazyLock` submembers.
+    pub lazy:Option<syn::Path>,
+    /// Flag that forwards the methods to every element of the submember collection, holding the `broadcast` key for diagnostics
+    pub broadcast:Option<Ident>,
+    /// Aggregators of the broadcast methods that return a value
+    pub aggregate:Vec<MethodAggregate>,
+    /// Members tried in order after the designated one, by the methods that return `Option`, `Result` or `bool`
+    pub chain:Vec<ChainLink>,
+    /// Result of a chain whose links return errors only. It's either `last`, `first`, or the path of a function
+    /// that receives the `Vec` of errors and returns the error of the method.
+    pub chain_error:Option<syn::Path>,
+    /// Function called with the names of the method and the link that answered a chain
+    pub chain_trace:Option<syn::Path>,
+    /// Flag that makes the component of the field swappable at runtime, holding the `swappable` key for diagnostics
+    pub swappable:Option<Ident>
+}
+
+impl ForwardingOptions
+{
+    /// Checks if `key` is the name of a forwarding option.
+    pub fn is_option_key(key:&Ident) -> bool
+    {
+        key == FORWARD_OPTION_RC_PROJECT || key == FORWARD_OPTION_ARC_PROJECT || key == FORWARD_OPTION_CONSTRUCT ||
+        key == FORWARD_OPTION_REWRAP || key == FORWARD_OPTION_FALLBACK || key == FORWARD_OPTION_MAP ||
+        key == FORWARD_OPTION_ROUTE || Self::is_accessor_key(key) || key == FORWARD_OPTION_CELL || key == FORWARD_OPTION_ON_ERROR ||
+        key == FORWARD_OPTION_OPTIONAL || key == FORWARD_OPTION_METHOD_FALLBACK || key == FORWARD_OPTION_LAZY ||
+        key == FORWARD_OPTION_BROADCAST || key == FORWARD_OPTION_AGGREGATE || key == FORWARD_OPTION_CHAIN ||
+        key == FORWARD_OPTION_CHAIN_ERROR || key == FORWARD_OPTION_CHAIN_TRACE || key == FORWARD_OPTION_SWAPPABLE
+    }
+
+    /// Checks if `key` names an accessor of the component (`via`, `via_ref` or `via_mut`).
+    pub fn is_accessor_key(key:&Ident) -> bool
+    {
+        key == FORWARD_OPTION_VIA || key == FORWARD_OPTION_VIA_REF || key == FORWARD_OPTION_VIA_MUT
+    }
+
+    /// Checks if the component is reached through accessors, instead of the submember.
+    pub(crate) fn has_accessors(&self) -> bool
+    {
+        self.via.is_some() || self.via_ref.is_some() || self.via_mut.is_some()
+    }
+
+    /// Accessor of the component for a method with the given receiver
+    pub(crate) fn find_accessor(&self, receiver_kind:ReceiverKind) -> Option<&syn::Expr>
+    {
+        match receiver_kind {
+            ReceiverKind::Ref => self.via_ref.as_ref().or(self.via.as_ref()),
+            ReceiverKind::RefMut => self.via_mut.as_ref().or(self.via.as_ref()),
+            _ => self.via.as_ref()
+        }
+    }
+
+    /// First accessor declared, for naming the component in diagnostics
+    pub(crate) fn first_accessor(&self) -> Option<&syn::Expr>
+    {
+        self.via.as_ref().or(self.via_ref.as_ref()).or(self.via_mut.as_ref())
+    }
+
+    /// Checks if `key` names an option with a parenthesized list of per-method settings.
+    pub fn is_list_option_key(key:&Ident) -> bool
+    {
+        key == FORWARD_OPTION_MAP || key == FORWARD_OPTION_ROUTE || key == FORWARD_OPTION_METHOD_FALLBACK || key == FORWARD_OPTION_AGGREGATE ||
+        key == FORWARD_OPTION_CHAIN
+    }
+
+    /// Checks if `key` names an option that is declared without a value, like `broadcast`.
+    pub fn is_flag_option_key(key:&Ident) -> bool
+    {
+        key == FORWARD_OPTION_BROADCAST || key == FORWARD_OPTION_SWAPPABLE
+    }
+
+    /// Mapping of the trait method `method`, if it's forwarded to a differently named method
+    pub(crate) fn find_mapping(&self, method:&Ident) -> Option<&MethodMapping>
+    {
+        self.map.iter().find(|mapping| mapping.method == *method)
+    }
+
+    /// Member that receives the trait method `method`, if it's routed to another member
+    pub(crate) fn find_route(&self, method:&Ident) -> Option<&MethodRoute>
+    {
+        self.route.iter().find(|route| route.method == *method)
+    }
+
+    /// Proxy that guards the submember, following the `cell` and `on_error` options
+    pub(crate) fn cell_proxy(&self) -> Option<CellProxy<'_>>
+    {
+        let kind = CellKind::from_option(self.cell.as_ref()?)?;
+        let on_error = self.on_error.as_ref().filter(|on_error| !on_error.is_ident(FORWARD_ON_ERROR_PANIC));
+        Some(CellProxy{kind, on_error})
+    }
+
+    /// Proxy that unwraps or upgrades the submember, following the `optional` and `fallback` options
+    pub(crate) fn optional_proxy(&self) -> Option<OptionalProxy<'_>>
+    {
+        let kind = OptionalKind::from_option(self.optional.as_ref()?)?;
+        Some(OptionalProxy{kind, options:self})
+    }
+
+    /// Proxy that initializes the lazy submember, following the `lazy` option
+    pub(crate) fn lazy_proxy(&self) -> Option<LazyProxy<'_>>
+    {
+        let lazy = self.lazy.as_ref()?;
+        Some(LazyProxy{init:Some(lazy).filter(|lazy| !lazy.is_ident(FORWARD_LAZY_FORCE))})
+    }
+
+    /// Proxy that calls every element of the submember collection, following the `broadcast` and `aggregate` options
+    pub(crate) fn broadcast_proxy(&self) -> Option<BroadcastProxy<'_>>
+    {
+        self.broadcast.as_ref().map(|_| BroadcastProxy{options:self})
+    }
+
+    /// Proxy that tries the designated member and the links of the chain in order, following the `chain` options
+    pub(crate) fn chain_proxy(&self) -> Option<ChainProxy<'_>>
+    {
+        if self.chain.is_empty() {None} else {Some(ChainProxy{options:self})}
+    }
+
+    /// Type of the component reached through the `optional`, `cell` and `lazy` wrappers of the submember,
+    /// like `T` in `Option<Box<T>>`, `Weak<RefCell<T>>` or `OnceCell<T>`, or the type of the elements of a broadcast
+    /// collection, like `T` in `Vec<Box<T>>`. It's `None` when the submember isn't wrapped.
+    pub(crate) fn proxied_component_type<'a>(&self, member_ty:&'a syn::Type) -> Option<&'a syn::Type>
+    {
+        if self.lazy.is_some() {
+            return peel_lazy_type(member_ty);
+        }
+
+        if self.swappable.is_some() {
+            return peel_slot_type(member_ty);
+        }
+
+        if self.broadcast.is_some() {
+            return collection_parts(member_ty).map(|(_, element_ty)| peel_pointer_type(element_ty));
+        }
+
+        if self.optional.is_none() && self.cell.is_none() {
+            return None;
+        }
+
+        let mut component_ty = member_ty;
+        if self.optional.is_some() {
+            component_ty = peel_pointer_type(peel_optional_type(component_ty)?);
+        }
+
+        if self.cell.is_some() {
+            component_ty = peel_cell_type(component_ty)?;
+        }
+        Some(component_ty)
+    }
+
+    /// Checks that the methods named by `map` and `route` are declared in the trait, that they are named once by each option,
+    /// and that they aren't `excluded` from forwarding. It also checks that the cell options aren't combined with the
+    /// options that reach the component in other ways.
+    pub(crate) fn validate_methods(&self, trait_info:&trait_info_gen::SimpleTraitInfo, excluded:&[Ident]) -> syn::Result<()>
+    {
+        if let Some(on_error) = self.on_error.as_ref().filter(|_| self.cell.is_none()) {
+            return Err(syn::Error::new_spanned(on_error, "The `on_error` option requires the `cell` option."));
+        }
+
+        if let Some(cell) = self.cell.as_ref().filter(|_| !self.map.is_empty() || self.has_accessors()) {
+            return Err(syn::Error::new_spanned(cell, "The `cell` option cannot be combined with `map` or the accessors of the component."));
+        }
+
+        if let Some(optional) = self.optional.as_ref().filter(|_| !self.map.is_empty() || self.has_accessors()) {
+            return Err(syn::Error::new_spanned(optional, "The `optional` option cannot be combined with `map` or the accessors of the component."));
+        }
+
+        if let Some(aggregate) = self.aggregate.first().filter(|_| self.broadcast.is_none()) {
+            return Err(syn::Error::new(aggregate.method.span(), "The `aggregate` option requires the `broadcast` option."));
+        }
+
+        if let Some(swappable) = self.swappable.as_ref().filter(|_| !self.map.is_empty() || self.has_accessors() || self.cell.is_some() ||
+            self.optional.is_some() || self.lazy.is_some() || self.broadcast.is_some() || !self.chain.is_empty()
+        ) {
+            return Err(syn::Error::new(swappable.span(), "The `swappable` option cannot be combined with `map`, `cell`, `optional`, `lazy`, `broadcast`, `chain` or the accessors of the component."));
+        }
+
+        let chain_settings = self.chain_error.as_ref().or(self.chain_trace.as_ref());
+        if let Some(chain_setting) = chain_settings.filter(|_| self.chain.is_empty()) {
+            return Err(syn::Error::new_spanned(chain_setting, "The `chain_error` and `chain_trace` options require the `chain` option."));
+        }
+
+        if let Some(link) = self.chain.first().filter(|_| !self.map.is_empty() || self.has_accessors() || self.cell.is_some() ||
+            self.optional.is_some() || self.lazy.is_some() || self.broadcast.is_some()
+        ) {
+            return Err(syn::Error::new(link.member.member_span(), "The `chain` option cannot be combined with `map`, `cell`, `optional`, `lazy`, `broadcast` or the accessors of the component."));
+        }
+
+        if let Some(broadcast) = self.broadcast.as_ref().filter(
+            |_| !self.map.is_empty() || self.has_accessors() || self.cell.is_some() || self.optional.is_some() || self.lazy.is_some()
+        ) {
+            return Err(syn::Error::new(broadcast.span(), "The `broadcast` option cannot be combined with `map`, `cell`, `optional`, `lazy` or the accessors of the component."));
+        }
+
+        if let Some(lazy) = self.lazy.as_ref().filter(
+            |_| !self.map.is_empty() || self.has_accessors() || self.cell.is_some() || self.optional.is_some()
+        ) {
+            return Err(syn::Error::new_spanned(lazy, "The `lazy` option cannot be combined with `map`, `cell`, `optional` or the accessors of the component."));
+        }
+
+        if let Some(cell) = self.cell.as_ref().filter(|_| self.optional.as_ref().is_some_and(|optional| optional.is_ident(crate::optional_member::FORWARD_OPTIONAL_OPTION))) {
+            return Err(syn::Error::new_spanned(cell, "The `cell` option can only be combined with `optional = weak`."));
+        }
+
+        if self.optional.is_none() {
+            let fallback_span = self.fallback.as_ref().map(|fallback| fallback.to_token_stream()).or(
+                self.method_fallback.first().map(|method_fallback| method_fallback.method.to_token_stream())
+            );
+            if let Some(fallback_span) = fallback_span {
+                return Err(syn::Error::new_spanned(fallback_span, "Fallbacks require the `optional` option on struct members."));
+            }
+        }
+
+        let trait_default = self.fallback.iter().chain(self.method_fallback.iter().map(|method_fallback| &method_fallback.fallback)).find(
+            |fallback| fallback.is_ident(FORWARD_FALLBACK_TRAIT_DEFAULT)
+        );
+        if let Some(trait_default) = trait_default {
+            return Err(syn::Error::new_spanned(trait_default,
+                "The default bodies of the trait methods cannot be used as fallbacks, as `trait_info` only records their signatures. Expected `default`, `panic` or a function that takes the receiver and the arguments of the method."
+            ));
+        }
+
+        let mapped = self.map.iter().map(|mapping| &mapping.method).collect::<Vec<_>>();
+        let routed = self.route.iter().map(|route| &route.method).collect::<Vec<_>>();
+        let fallen_back = self.method_fallback.iter().map(|method_fallback| &method_fallback.method).collect::<Vec<_>>();
+        let aggregated = self.aggregate.iter().map(|aggregate| &aggregate.method).collect::<Vec<_>>();
+        [(mapped, "mapped"), (routed, "routed"), (fallen_back, "given a fallback"), (aggregated, "aggregated")].into_iter().try_for_each(|(methods, action)| {
+            methods.iter().enumerate().try_for_each(|(index, method)| {
+                let message = if !trait_info.functions.iter().any(|fsig| fsig.ident == **method) {
+                    format!("Method `{}` is not declared in trait `{}`.", method, trait_info.ident)
+                }
+                else if methods[..index].contains(method) {
+                    format!("Method `{}` is {} more than once.", method, action)
+                }
+                else if excluded.contains(method) {
+                    format!("Method `{}` is {}, but it's excluded from forwarding by the filter of `{}`.", method, action, trait_info.ident)
+                }
+                else {
+                    return Ok(());
+                };
+                Err(syn::Error::new(method.span(), message))
+            })
+        })
+    }
+
+    /// Parses the option named by `key`, either `= value`, the parenthesized list of `map(..)` and `route(..)`, or a flag like `broadcast`.
+    pub fn parse_option_entry(&mut self, key:&Ident, input: syn::parse::ParseStream) -> syn::Result<()>
+    {
+        if key == FORWARD_OPTION_MAP {
+            self.map.extend(parse_mappings(input)?);
+            return Ok(());
+        }
+
+        if key == FORWARD_OPTION_ROUTE {
+            self.route.extend(parse_routes(input)?);
+            return Ok(());
+        }
+
+        if key == FORWARD_OPTION_METHOD_FALLBACK {
+            self.method_fallback.extend(parse_method_fallbacks(input)?);
+            return Ok(());
+        }
+
+        if key == FORWARD_OPTION_AGGREGATE {
+            self.aggregate.extend(parse_method_aggregates(input)?);
+            return Ok(());
+        }
+
+        if key == FORWARD_OPTION_CHAIN {
+            self.chain.extend(parse_chain(input)?);
+            return Ok(());
+        }
+
+        // the list options are extended, any other option is set once
+        if self.is_option_set(key) {
+            return Err(syn::Error::new(key.span(), format!("Duplicate option `{}`.", key)));
+        }
+
+        if key == FORWARD_OPTION_BROADCAST {
+            self.broadcast = Some(key.clone());
+            return Ok(());
+        }
+
+        if key == FORWARD_OPTION_SWAPPABLE {
+            self.swappable = Some(key.clone());
+            return Ok(());
+        }
+
+        let _eq = input.parse::<syn::token::Eq>()?;
+        self.parse_option_value(key, input)
+    }
+
+    /// Checks if the single-valued option named by `key` has been set already
+    fn is_option_set(&self, key:&Ident) -> bool
+    {
+        [
+            (FORWARD_OPTION_RC_PROJECT, self.rc_project.is_some()),
+            (FORWARD_OPTION_ARC_PROJECT, self.arc_project.is_some()),
+            (FORWARD_OPTION_CONSTRUCT, self.construct.is_some()),
+            (FORWARD_OPTION_REWRAP, self.rewrap.is_some()),
+            (FORWARD_OPTION_FALLBACK, self.fallback.is_some()),
+            (FORWARD_OPTION_CELL, self.cell.is_some()),
+            (FORWARD_OPTION_OPTIONAL, self.optional.is_some()),
+            (FORWARD_OPTION_ON_ERROR, self.on_error.is_some()),
+            (FORWARD_OPTION_LAZY, self.lazy.is_some()),
+            (FORWARD_OPTION_BROADCAST, self.broadcast.is_some()),
+            (FORWARD_OPTION_CHAIN_ERROR, self.chain_error.is_some()),
+            (FORWARD_OPTION_CHAIN_TRACE, self.chain_trace.is_some()),
+            (FORWARD_OPTION_SWAPPABLE, self.swappable.is_some()),
+            (FORWARD_OPTION_VIA, self.via.is_some()),
+            (FORWARD_OPTION_VIA_REF, self.via_ref.is_some()),
+            (FORWARD_OPTION_VIA_MUT, self.via_mut.is_some()),
+        ].iter().any(|(option, set)| *set && key == option)
+    }
+
+    /// Parses the value of the option named by `key`, the `=` token has been consumed already.
+    pub fn parse_option_value(&mut self, key:&Ident, input: syn::parse::ParseStream) -> syn::Result<()>
+    {
+        if key == FORWARD_OPTION_RC_PROJECT {
+            self.rc_project = Some(input.parse()?);
+        }
+        else if key == FORWARD_OPTION_ARC_PROJECT {
+            self.arc_project = Some(input.parse()?);
+        }
+        else if key == FORWARD_OPTION_CONSTRUCT {
+            self.construct = Some(input.parse()?);
+        }
+        else if key == FORWARD_OPTION_REWRAP {
+            self.rewrap = Some(input.parse()?);
+        }
+        else if key == FORWARD_OPTION_FALLBACK {
+            self.fallback = Some(input.parse()?);
+        }
+        else if key == FORWARD_OPTION_CELL {
+            let cell:syn::Path = input.parse()?;
+            if CellKind::from_option(&cell).is_none() {
+                return Err(syn::Error::new_spanned(cell, "Expected `cell = refcell`, `cell = mutex` or `cell = rwlock`."));
+            }
+            self.cell = Some(cell);
+        }
+        else if key == FORWARD_OPTION_OPTIONAL {
+            let optional:syn::Path = input.parse()?;
+            if OptionalKind::from_option(&optional).is_none() {
+                return Err(syn::Error::new_spanned(optional, "Expected `optional = option` or `optional = weak`."));
+            }
+            self.optional = Some(optional);
+        }
+        else if key == FORWARD_OPTION_ON_ERROR {
+            self.on_error = Some(input.parse()?);
+        }
+        else if key == FORWARD_OPTION_LAZY {
+            self.lazy = Some(input.parse()?);
+        }
+        else if key == FORWARD_OPTION_CHAIN_ERROR {
+            self.chain_error = Some(input.parse()?);
+        }
+        else if key == FORWARD_OPTION_CHAIN_TRACE {
+            self.chain_trace = Some(input.parse()?);
+        }
+        else if key == FORWARD_OPTION_VIA {
+            self.via = Some(input.parse()?);
+        }
+        else if key == FORWARD_OPTION_VIA_REF {
+            self.via_ref = Some(input.parse()?);
+        }
+        else if key == FORWARD_OPTION_VIA_MUT {
+            self.via_mut = Some(input.parse()?);
+        }
+        else {
+            return Err(syn::Error::new(key.span(), format!("Unknown forwarding option `{}`.", key)));
+        }
+
+        Ok(())
+    }
+
+    /// Parses a single `key = value` or `map(..)` entry
+    pub fn parse_option(&mut self, input: syn::parse::ParseStream) -> syn::Result<()>
+    {
+        let key:Ident = input.parse()?;
+        self.parse_option_entry(&key, input)
+    }
+}
+
+/// Parse the comma separated list of options inside the `options[]` block
+impl syn::parse::Parse for ForwardingOptions
+{
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut options = ForwardingOptions::default();
+        while !input.is_empty() {
+            options.parse_option(input)?;
+            if !input.is_empty() {
+                input.parse::<syn::token::Comma>()?;
+            }
+        }
+        Ok(options)
+    }
+}
+
+impl quote::ToTokens for ForwardingOptions
+{
+    fn to_tokens(&self, tokens: &mut TkStream) {
+        if let Some(rc_project) = &self.rc_project {
+            tokens.extend(quote::quote!(rc_project = #rc_project,));
+        }
+
+        if let Some(arc_project) = &self.arc_project {
+            tokens.extend(quote::quote!(arc_project = #arc_project,));
+        }
+
+        if let Some(construct) = &self.construct {
+            tokens.extend(quote::quote!(construct = #construct,));
+        }
+
+        if let Some(rewrap) = &self.rewrap {
+            tokens.extend(quote::quote!(rewrap = #rewrap,));
+        }
+
+        if let Some(fallback) = &self.fallback {
+            tokens.extend(quote::quote!(fallback = #fallback,));
+        }
+
+        if !self.map.is_empty() {
+            let mappings = &self.map;
+            tokens.extend(quote::quote!(map(#(#mappings),*),));
+        }
+
+        if !self.route.is_empty() {
+            let routes = &self.route;
+            tokens.extend(quote::quote!(route(#(#routes),*),));
+        }
+
+        if let Some(cell) = &self.cell {
+            tokens.extend(quote::quote!(cell = #cell,));
+        }
+
+        if let Some(on_error) = &self.on_error {
+            tokens.extend(quote::quote!(on_error = #on_error,));
+        }
+
+        if let Some(optional) = &self.optional {
+            tokens.extend(quote::quote!(optional = #optional,));
+        }
+
+        if !self.method_fallback.is_empty() {
+            let method_fallbacks = &self.method_fallback;
+            tokens.extend(quote::quote!(method_fallback(#(#method_fallbacks),*),));
+        }
+
+        if let Some(lazy) = &self.lazy {
+            tokens.extend(quote::quote!(lazy = #lazy,));
+        }
+
+        if let Some(broadcast) = &self.broadcast {
+            tokens.extend(quote::quote!(#broadcast,));
+        }
+
+        if !self.aggregate.is_empty() {
+            let aggregates = &self.aggregate;
+            tokens.extend(quote::quote!(aggregate(#(#aggregates),*),));
+        }
+
+        if !self.chain.is_empty() {
+            let links = &self.chain;
+            tokens.extend(quote::quote!(chain(#(#links),*),));
+        }
+
+        if let Some(chain_error) = &self.chain_error {
+            tokens.extend(quote::quote!(chain_error = #chain_error,));
+        }
+
+        if let Some(chain_trace) = &self.chain_trace {
+            tokens.extend(quote::quote!(chain_trace = #chain_trace,));
+        }
+
+        if let Some(swappable) = &self.swappable {
+            tokens.extend(quote::quote!(#swappable,));
+        }
+
+        if let Some(via) = &self.via {
+            tokens.extend(quote::quote!(via = #via,));
+        }
+
+        if let Some(via_ref) = &self.via_ref {
+            tokens.extend(quote::quote!(via_ref = #via_ref,));
+        }
+
+        if let Some(via_mut) = &self.via_mut {
+            tokens.extend(quote::quote!(via_mut = #via_mut,));
+        }
+    }
+}
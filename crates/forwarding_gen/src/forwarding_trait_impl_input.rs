@@ -10,6 +10,8 @@ use syn::{ItemImpl, Ident};
 use trait_info_gen::TraitPathAttrib as TraitPathAttrib;
 use crate::forwarding_derive_input::FORWARD_DERIVE_PATH_ATTRIB as FORWARD_TRAIT_IMPL_PATH_ATTRIB;
 use crate::forwarding_derive_input::FORWARD_DERIVE_PATH_DEFAULT as FORWARD_TRAIT_IMPL_PATH_DEFAULT;
+use crate::method_member_adapter::ForwardingMember;
+use crate::forwarding_options::{ForwardingOptions, FORWARD_OPTION_CHAIN};
 use sha3::{Digest, Sha3_256};
 use base32ct::{Base32Unpadded, Encoding};
 
@@ -17,47 +19,82 @@ pub const FORWARD_TRAIT_IMPL_PROCESS_MACRO_NAME: &'static str  = "ForwardingTrai
 
 struct ForwardingTraitAttribParams
 {
-    submember_id:Ident,
+    submember:ForwardingMember,
+    options:ForwardingOptions,
     process_macro_path: syn::Path
 }
 
-fn parse_parenthized_mod_path(input: syn::parse::ParseStream) -> syn::Result<syn::Path>
-{    
-    let _comma = input.parse::<syn::token::Comma>()?;
-    let param_id = input.parse::<Ident>()?;
-    if param_id.eq(FORWARD_TRAIT_IMPL_PATH_ATTRIB) {
-        let param_content;
-        let _cparen = syn::parenthesized!(param_content in input);        
-        param_content.parse::<syn::Path>()
-    }
-    else
-    {
-        syn::Result::Err(syn::Error::new(input.span(), "Path parameter malformed."))
-    }
-}
-
 /// Parse from attribute params inner content
 impl syn::parse::Parse for ForwardingTraitAttribParams
 {
-    /// Read the submember identifier, and the process_macro_path if any.
+    /// Read the submember identifier, the forwarding options and the process_macro_path if any.
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        let submemberid:Ident = input.parse()?;        
+        // the submember is omitted when the component is reached through accessors (`via = self.parts.wing`),
+        // or when it's the first link of a chain (`chain(cache, disk)`)
+        let chain_first = input.peek(Ident) && input.peek2(syn::token::Paren) && input.fork().parse::<Ident>().is_ok_and(|key| key == FORWARD_OPTION_CHAIN);
+        let submember_op:Option<ForwardingMember> = if (input.peek(Ident) && input.peek2(syn::token::Eq)) || chain_first {None} else {Some(input.parse()?)};
+        let mut options = ForwardingOptions::default();
+        let mut macropath_op: Option<syn::Path> = None;
+
+        // parameters after the submember are preceded by a comma
+        let mut leading_param = submember_op.is_none();
+        while !input.is_empty() {
+            if !leading_param {
+                let _comma = input.parse::<syn::token::Comma>()?;
+                if input.is_empty() { break; }
+            }
+            leading_param = false;
+
+            let param_id = input.parse::<Ident>()?;
+            if param_id.eq(FORWARD_TRAIT_IMPL_PATH_ATTRIB) {
+                let param_content;
+                let _cparen = syn::parenthesized!(param_content in input);
+                macropath_op = Some(param_content.parse::<syn::Path>()?);
+            }
+            else if ForwardingOptions::is_option_key(&param_id) {
+                options.parse_option_entry(&param_id, input)?;
+            }
+            else {
+                return syn::Result::Err(syn::Error::new(param_id.span(), "Unknown parameter in forward_trait attribute."));
+            }
+        }
         
-        let mut macropath: syn::Path = parse_parenthized_mod_path(input).unwrap_or(
+        // the setters of swappable fields are inherent methods, generated once by the derive macro
+        if let Some(swappable) = &options.swappable {
+            return Err(syn::Error::new(swappable.span(), "The `swappable` option is only supported by `forward_derive`, which generates the setters of the field."));
+        }
+
+        let submember = match (submember_op, options.first_accessor()) {
+            (Some(submember), _) => submember,
+            (None, None) if !options.chain.is_empty() => {
+                let first_link = options.chain.remove(0);
+                if first_link.elements {
+                    return Err(syn::Error::new(first_link.member.member_span(), "The first link of the chain is the designated member, which receives the methods that aren't chained, so it cannot be spread with `..`."));
+                }
+                first_link.member
+            },
+            (None, Some(accessor)) => crate::member_accessor::accessor_member(accessor)?,
+            (None, None) => {
+                return Err(input.error("Required a submember, or an accessor of the component as `via = self.parts.component`."));
+            }
+        };
+
+        let mut macropath: syn::Path = macropath_op.unwrap_or(
             // default macro path is "hereditary"
-            syn::Path::from(syn::Ident::new(FORWARD_TRAIT_IMPL_PATH_DEFAULT, submemberid.span()))
+            syn::Path::from(syn::Ident::new(FORWARD_TRAIT_IMPL_PATH_DEFAULT, submember.member_span()))
         );
 
         // append the macro processing function name
-        macropath.segments.push(syn::Ident::new(FORWARD_TRAIT_IMPL_PROCESS_MACRO_NAME, submemberid.span()).into());
+        macropath.segments.push(syn::Ident::new(FORWARD_TRAIT_IMPL_PROCESS_MACRO_NAME, submember.member_span()).into());
 
-        Ok(Self{submember_id:submemberid, process_macro_path:macropath})
+        Ok(Self{submember, options, process_macro_path:macropath})
     }
 }
 
 pub struct ForwardingTraitImplInput
 {    
-    pub submember_id:Ident,
+    pub submember:ForwardingMember,
+    pub options:ForwardingOptions,
     pub trait_path:TraitPathAttrib,
     pub process_macro_path: syn::Path,
     pub trait_impl:ItemImpl,
@@ -85,7 +122,8 @@ impl ForwardingTraitImplInput
         let traitpath = TraitPathAttrib::from_path::<true>(tpath, traitimpl.impl_token.span.clone())?;
 
         Ok(Self{
-            submember_id:attribparams.submember_id,
+            submember:attribparams.submember,
+            options:attribparams.options,
             trait_path: traitpath,
             process_macro_path:attribparams.process_macro_path,
             trait_impl:traitimpl
@@ -94,8 +132,9 @@ impl ForwardingTraitImplInput
 
     fn generate_macro_hash(&self) -> String
     {
-        let strbulk = format!("member:{},traithpath:{},macropath{},impl{}", 
-            self.submember_id.to_string(),
+        let strbulk = format!("member:{},options:{},traithpath:{},macropath{},impl{}", 
+            self.submember.to_token_stream(),
+            self.options.to_token_stream(),
             self.trait_path.gen_path().to_token_stream(),
             self.process_macro_path.to_token_stream(),
             self.trait_impl.to_token_stream()
@@ -110,10 +149,11 @@ impl ForwardingTraitImplInput
     pub fn generate_macro_code(&self) -> TkStream
     {
         let bridge_macro_name_str = format!("ForwardingTraitImpl_{}", self.generate_macro_hash());
-        let bridge_macro_id = syn::Ident::new(&bridge_macro_name_str.as_str(), self.submember_id.span());
+        let bridge_macro_id = syn::Ident::new(&bridge_macro_name_str.as_str(), self.submember.member_span());
 
         let receiver_macro = &self.process_macro_path;
-        let member_id = &self.submember_id;
+        let member_id = &self.submember;
+        let options = &self.options;
         let trait_impl_block = &self.trait_impl;
         let trait_info_macro = self.trait_path.gen_info_macro_path();
 
@@ -121,8 +161,8 @@ impl ForwardingTraitImplInput
         quote::quote!(
             macro_rules! #bridge_macro_id
             {
-                ([$member_id:ident],[$($traitinfo:tt)*]) => {
-                    #receiver_macro!(impltrait[#trait_impl_block];$member_id;traitdef[$($traitinfo)*]);
+                ([$($member_id:tt)*],[$($traitinfo:tt)*]) => {
+                    #receiver_macro!(impltrait[#trait_impl_block];$($member_id)*;options[#options];traitdef[$($traitinfo)*]);
                 };
             }
 
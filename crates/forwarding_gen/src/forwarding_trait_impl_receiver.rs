@@ -10,7 +10,8 @@ use syn::{ItemImpl, Ident, ImplItem};
 use sha3::{Digest, Sha3_256};
 use base32ct::{Base32Unpadded, Encoding};
 
-use crate::method_member_adapter::{TraitMethodImplMacros, MethodAdaptError};
+use crate::method_member_adapter::{TraitMethodImplMacros, MethodForwardingContext, MethodAdaptError, ForwardingMember, generate_unforwardable_error};
+use crate::forwarding_options::ForwardingOptions;
 
 /// Registers already implemented methods from trait implementation expression
 #[allow(dead_code)]
@@ -59,22 +60,31 @@ impl ImplementedItems
 
     fn generate_trait_methods(
         &self,
-        submember:&Ident,
-        trait_info_obj: &trait_info_gen::SimpleTraitInfo
+        forwarding_context:&MethodForwardingContext,
+        trait_info_obj: &trait_info_gen::SimpleTraitInfo,
+        error_span:proc_macro2::Span
     ) -> syn::Result< Vec<TraitMethodImplMacros> >
     {
+        let mut method_pairs:Vec<TraitMethodImplMacros> = Vec::new();
+        let mut failures:Vec<(Ident, MethodAdaptError)> = Vec::new();
         trait_info_obj.functions.iter().filter(
             |&fsig| self.methods.contains(&fsig.ident) == false
-        ).filter_map(
-            |fsig| -> Option<syn::Result<TraitMethodImplMacros>> {
-                // Avoid collecting non dispatchable
-                match TraitMethodImplMacros::create(submember, fsig, &self.base_hash) {
-                    Ok(stk) => { Some(Ok(stk)) },
-                    Err(MethodAdaptError::NotDispatchable(_)) => {None},
-                    Err(err) => { Some( Err(err.into()) ) }                    
-                }
+        ).for_each(
+            |fsig| match TraitMethodImplMacros::create(forwarding_context, fsig, &self.base_hash) {
+                Ok(stk) => { method_pairs.push(stk); },
+                // Avoid collecting non dispatchable, they are left to the implementation
+                Err(MethodAdaptError::NotDispatchable(_)) => {},
+                Err(err) => { failures.push((fsig.ident.clone(), err)); }
             }
-        ).collect::<syn::Result<_> >()
+        );
+
+        if !failures.is_empty() {
+            return Err(generate_unforwardable_error(
+                error_span, &trait_info_obj.ident, &forwarding_context.describe_target(), failures
+            ));
+        }
+
+        Ok(method_pairs)
     }
 }
 
@@ -82,7 +92,8 @@ impl ImplementedItems
 pub struct ForwardingTraitImplReceiver
 {
     pub trait_impl:ItemImpl,
-    pub submember_id:Ident,
+    pub submember:ForwardingMember,
+    pub options:ForwardingOptions,
     pub trait_info_obj:trait_info_gen::SimpleTraitInfo
 }
 
@@ -91,9 +102,29 @@ impl ForwardingTraitImplReceiver
     /// Builds a trait implementation with new additional method wrappers as items.
     pub fn generate_impl_methods(&self) -> syn::Result<TkStream>
     {
+        let trait_path = self.trait_impl.trait_.as_ref().map(|(_, tpath, _)| tpath).ok_or(
+            syn::Error::new(self.trait_impl.impl_token.span, "Required a Trait name.")
+        )?;
+
+        // `unsafe impl` acknowledges that the submember upholds the safety contract of the trait
+        if self.trait_info_obj.unsafety && self.trait_impl.unsafety.is_none() {
+            return Err(syn::Error::new(self.trait_impl.impl_token.span, format!(
+                "Trait `{}` is unsafe, so forwarding it to the field `{}` requires declaring the implementation as `unsafe impl`.",
+                self.trait_info_obj.ident, self.submember.member_name()
+            )));
+        }
+
+        self.options.validate_methods(&self.trait_info_obj, &[])?;
+
+        let forwarding_context = MethodForwardingContext{
+            submember:&self.submember,
+            trait_path,
+            options:&self.options
+        };
+
         let extended_method_pairs = ImplementedItems::create(
             &self.trait_impl
-        ).generate_trait_methods(&self.submember_id, &self.trait_info_obj)?;
+        ).generate_trait_methods(&forwarding_context, &self.trait_info_obj, self.trait_impl.impl_token.span)?;
 
         if extended_method_pairs.len() == 0 {
             Ok(self.trait_impl.to_token_stream())
@@ -129,8 +160,9 @@ impl syn::parse::Parse for ForwardingTraitImplReceiver
     /// Process a macro syntax that contains the trait implementation, the submember identifier and the trait definition.
     /// Parameters are separated by semi token `;`, where:
     /// * The first parameter is implemented trait (delimited by `impltrait[]` block).
-    /// * The second is the identifier of the submember that implements the trait methods.
-    /// * The third is a trait information obtained by reflection macro before (delimited by `traitdef[]` block).
+    /// * The second is the identifier of the submember that implements the trait methods (optionally followed by its type).
+    /// * The third is the list of forwarding options (delimited by `options[]` block).
+    /// * The fourth is a trait information obtained by reflection macro before (delimited by `traitdef[]` block).
     /// 
     /// 
     /// ```
@@ -145,6 +177,7 @@ impl syn::parse::Parse for ForwardingTraitImplReceiver
     ///     }
     /// ];
     /// submember_id;
+    /// options[rc_project = project_fn];
     /// traitdef[
     ///      CashFlow {
     ///         FUNCS[
@@ -161,6 +194,7 @@ impl syn::parse::Parse for ForwardingTraitImplReceiver
         mod kw_inner
         {
             syn::custom_keyword!(impltrait);
+            syn::custom_keyword!(options);
             syn::custom_keyword!(traitdef);            
         }
         
@@ -172,8 +206,15 @@ impl syn::parse::Parse for ForwardingTraitImplReceiver
         let _sep0 = input.parse::<syn::token::Semi>()?; //;
 
         // sub member identifier
-        let submember_id:Ident = input.parse()?;
+        let submember:ForwardingMember = input.parse()?;
         let _sep1 = input.parse::<syn::token::Semi>()?; //;
+
+        // options[]
+        let _optionstoken = input.parse::<kw_inner::options>()?;
+        let options_content;
+        let _options_brakets = syn::bracketed!(options_content in input);
+        let options = options_content.parse::<ForwardingOptions>()?;
+        let _sep2 = input.parse::<syn::token::Semi>()?; //;
         
         // traitdef[]
         let _traitdeftoken = input.parse::<kw_inner::traitdef>()?;
@@ -186,7 +227,7 @@ impl syn::parse::Parse for ForwardingTraitImplReceiver
         // process the trait info
         let trait_info_obj = trait_def_content.parse::<trait_info_gen::SimpleTraitInfo>()?;
 
-        Ok(Self{trait_impl:trait_impl, submember_id, trait_info_obj})
+        Ok(Self{trait_impl:trait_impl, submember, options, trait_info_obj})
         
     }
 }
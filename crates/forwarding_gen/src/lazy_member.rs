@@ -0,0 +1,93 @@
+/*
+Hereditary
+Autors: Francisco Leon <https://github.com/superoptimo>
+License Apache-2.0
+*/
+
+use proc_macro2::token_stream::TokenStream as TkStream;
+use syn::{Ident, Type};
+
+use crate::method_member_adapter::{ForwardingMember, MethodAdaptError, ReceiverKind};
+
+/// `lazy = force` forwards to a `LazyCell` or `LazyLock` field, which holds its own initializer
+pub const FORWARD_LAZY_FORCE: &str = "force";
+
+/// Cell type of a lazy field and the type of its component, like `OnceCell` and `T` in `OnceCell<T>`
+fn lazy_type_parts(member_ty:&Type) -> Option<(&Ident, &Type)>
+{
+    let syn::Type::Path(tpath) = member_ty else {return None};
+    let last = tpath.path.segments.last()?;
+    if !["OnceCell", "OnceLock", "LazyCell", "LazyLock"].iter().any(|cell_name| last.ident == cell_name) {
+        return None;
+    }
+
+    match &last.arguments {
+        syn::PathArguments::AngleBracketed(args) => match args.args.first()? {
+            syn::GenericArgument::Type(component_ty) => Some((&last.ident, component_ty)),
+            _ => None
+        },
+        _ => None
+    }
+}
+
+/// Type of the component initialized by a lazy field, like `T` in `OnceCell<T>` or `LazyLock<T, F>`
+pub(crate) fn peel_lazy_type(member_ty:&Type) -> Option<&Type>
+{
+    lazy_type_parts(member_ty).map(|(_, component_ty)| component_ty)
+}
+
+/// Access to a component that is built on first use, through the `OnceCell`, `OnceLock`, `LazyCell` or `LazyLock` of the submember
+pub(crate) struct LazyProxy<'a>
+{
+    /// function that builds the component of a `OnceCell` or `OnceLock`, or `None` for `lazy = force`
+    pub(crate) init:Option<&'a syn::Path>
+}
+
+impl LazyProxy<'_>
+{
+    /// Argument that passes the component to the trait method, after initializing it.
+    ///
+    /// With an initializer, `&self` methods call `get_or_init(init)`, `&mut self` methods call `get_mut()` once the component
+    /// is initialized, and `self` methods take the component with `into_inner()` or build it. `LazyCell` and `LazyLock`
+    /// fields are forced by dereferencing them, which doesn't support `self` methods.
+    pub(crate) fn generate_component_arg(
+        &self,
+        submember:&ForwardingMember,
+        receiver_kind:ReceiverKind,
+        method_name:&Ident
+    ) -> Result<TkStream, MethodAdaptError>
+    {
+        // `LazyCell` and `LazyLock` fields hold their initializer, while `OnceCell` and `OnceLock` fields require one
+        let cell_name = submember.member_ty.as_ref().and_then(lazy_type_parts).map(|(cell_name, _)| cell_name);
+        if let Some(cell_name) = cell_name {
+            let forced = cell_name == "LazyCell" || cell_name == "LazyLock";
+            if forced == self.init.is_some() {
+                let expected = if forced {"`lazy = force`"} else {"an initializer function, as in `lazy = init_fn`"};
+                return Err(MethodAdaptError::Unforwardable(method_name.span(), format!(
+                    "Method `{}` cannot be forwarded to the `{}` field `{}`, which requires {}.",
+                    method_name, cell_name, submember.member_name(), expected
+                )));
+            }
+        }
+
+        let member = &submember.member;
+        match (self.init, receiver_kind) {
+            (Some(init), ReceiverKind::Ref) => Ok(quote::quote!($self_token.#member.get_or_init(#init))),
+            (Some(init), ReceiverKind::RefMut) => Ok(quote::quote!({
+                let _ = $self_token.#member.get_or_init(#init);
+                ::core::option::Option::expect($self_token.#member.get_mut(), "the lazy component is initialized")
+            })),
+            (Some(init), ReceiverKind::Value) => Ok(quote::quote!($self_token.#member.into_inner().unwrap_or_else(#init))),
+            (None, ReceiverKind::Ref) => Ok(quote::quote!(&*$self_token.#member)),
+            (None, ReceiverKind::RefMut) => Ok(quote::quote!(&mut *$self_token.#member)),
+            (None, ReceiverKind::Value) => Err(MethodAdaptError::Unforwardable(method_name.span(), format!(
+                "Method `{}` takes `self`, which cannot take the component out of the lazy field `{}` forced by dereferencing it. It's supported for `OnceCell` fields with `lazy = init_fn`.",
+                method_name, submember.member_name()
+            ))),
+            _ => Err(MethodAdaptError::Unforwardable(method_name.span(), format!(
+                "Method `{}` cannot be forwarded to the lazy field `{}`, as it requires a `self`, `&self` or `&mut self` receiver.",
+                method_name, submember.member_name()
+            )))
+        }
+    }
+}
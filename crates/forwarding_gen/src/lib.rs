@@ -9,8 +9,29 @@ mod forwarding_trait_impl_receiver;
 mod forwarding_trait_impl_input;
 mod forwarding_derive_member_receiver;
 mod forwarding_derive_input;
+mod forwarding_options;
+mod self_type_mapping;
+mod variant_dispatch;
+mod trait_args_mapping;
+mod method_filter;
+mod method_mapping;
+mod member_accessor;
+mod cell_proxy;
+mod optional_member;
+mod lazy_member;
+mod broadcast_member;
+mod chain_member;
+mod swappable_slot;
+mod partial_impl;
 
 pub use forwarding_trait_impl_receiver::ForwardingTraitImplReceiver as ForwardingTraitImplReceiver;
 pub use forwarding_derive_member_receiver::ForwardingDeriveMemberReceiver as ForwardingDeriveMemberReceiver;
 pub use forwarding_derive_input::{ForwardingDeriveInput, ForwardingDeriveMemberTask};
 pub use forwarding_trait_impl_input::ForwardingTraitImplInput as ForwardingTraitImplInput;
+pub use method_member_adapter::ForwardingMember as ForwardingMember;
+pub use forwarding_options::ForwardingOptions as ForwardingOptions;
+pub use variant_dispatch::{ForwardingTarget, VariantArm, VariantHandling};
+pub use method_filter::{MethodFilter, MethodOverride};
+pub use method_mapping::{MethodMapping, MethodRoute, MethodFallback, MethodAggregate};
+pub use chain_member::ChainLink;
+pub use partial_impl::ForwardingPartialImplInput;
@@ -0,0 +1,79 @@
+/*
+Hereditary
+Autors: Francisco Leon <https://github.com/superoptimo>
+License Apache-2.0
+*/
+
+use proc_macro2::token_stream::TokenStream as TkStream;
+use proc_macro2::TokenTree;
+use quote::ToTokens;
+use syn::Expr;
+
+use crate::method_member_adapter::{ForwardingMember, ReceiverKind};
+
+/// Replaces the `self` tokens of the accessor with the receiver of the generated method,
+/// as `self` isn't visible across the hygiene of the method macros.
+fn replace_self_tokens(tokens:TkStream) -> TkStream
+{
+    tokens.into_iter().flat_map(|token| -> TkStream {
+        match token {
+            TokenTree::Ident(ident) if ident == "self" => quote::quote!($self_token),
+            TokenTree::Group(group) => {
+                let mut new_group = proc_macro2::Group::new(group.delimiter(), replace_self_tokens(group.stream()));
+                new_group.set_span(group.span());
+                TokenTree::Group(new_group).into_token_stream()
+            },
+            other => other.into_token_stream()
+        }
+    }).collect()
+}
+
+/// Expression that reaches the component through the accessor, in the form required by the receiver.
+///
+/// Accessors that don't start with `self` are members of the composite (`get()` is `self.get()`).
+/// Field paths are borrowed as places (`&self.parts.wing`), while method calls return a reference or
+/// a pointer to the component that is reborrowed (`&*self.inner()`).
+pub(crate) fn generate_accessor_access(accessor:&Expr, receiver_kind:ReceiverKind) -> TkStream
+{
+    let starts_with_self = accessor.to_token_stream().into_iter().next().is_some_and(
+        |token| matches!(&token, TokenTree::Ident(ident) if ident == "self")
+    );
+
+    let accessor_tokens = replace_self_tokens(accessor.to_token_stream());
+    let access_expr = if starts_with_self {accessor_tokens} else {quote::quote!($self_token.#accessor_tokens)};
+
+    let is_call = matches!(accessor, Expr::MethodCall(_) | Expr::Call(_));
+    match (receiver_kind, is_call) {
+        (ReceiverKind::Ref, true) => quote::quote!(&*(#access_expr)),
+        (ReceiverKind::Ref, false) => quote::quote!(&(#access_expr)),
+        (ReceiverKind::RefMut, true) => quote::quote!(&mut *(#access_expr)),
+        (ReceiverKind::RefMut, false) => quote::quote!(&mut (#access_expr)),
+        _ => quote::quote!((#access_expr))
+    }
+}
+
+/// Name of the component reached by the accessor, the last field or method of the path
+fn accessor_name(accessor:&Expr) -> Option<syn::Member>
+{
+    match accessor {
+        Expr::Field(field) => Some(field.member.clone()),
+        Expr::MethodCall(method_call) => Some(syn::Member::Named(method_call.method.clone())),
+        Expr::Call(call) => accessor_name(&call.func),
+        Expr::Path(epath) => epath.path.segments.last().filter(|segment| segment.ident != "self").map(
+            |segment| syn::Member::Named(segment.ident.clone())
+        ),
+        Expr::Index(index) => accessor_name(&index.expr),
+        Expr::Paren(paren) => accessor_name(&paren.expr),
+        _ => None
+    }
+}
+
+/// Member that designates the component of an implementation that is forwarded through accessors only,
+/// which is named after the accessor in the diagnostics.
+pub(crate) fn accessor_member(accessor:&Expr) -> syn::Result<ForwardingMember>
+{
+    let member = accessor_name(accessor).ok_or_else(|| syn::Error::new_spanned(
+        accessor, "Accessors are required to be a field path (`self.parts.wing`) or a method call (`self.inner()`)."
+    ))?;
+    Ok(ForwardingMember{member, member_ty:None})
+}
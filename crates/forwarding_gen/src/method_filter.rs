@@ -0,0 +1,161 @@
+/*
+Hereditary
+Autors: Francisco Leon <https://github.com/superoptimo>
+License Apache-2.0
+*/
+
+use proc_macro2::token_stream::TokenStream as TkStream;
+use syn::Ident;
+
+mod kw_filter
+{
+    syn::custom_keyword!(except);
+    syn::custom_keyword!(only);
+}
+
+/// Method excluded from forwarding, with the inherent method of the composite that implements it instead, if any.
+///
+/// Its syntax is `method = inherent_method`, or the bare `method` when it's implemented by the partial implementation
+/// of the trait. Filters also list the forwarded methods by their bare name, in `only(..)`.
+#[derive(Clone)]
+pub struct MethodOverride
+{
+    pub method:Ident,
+    pub inherent:Option<Ident>
+}
+
+impl syn::parse::Parse for MethodOverride
+{
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let method:Ident = input.parse()?;
+        let inherent = if input.peek(syn::Token![=]) {
+            let _eq = input.parse::<syn::Token![=]>()?;
+            Some(input.parse()?)
+        } else {None};
+
+        Ok(Self{method, inherent})
+    }
+}
+
+impl quote::ToTokens for MethodOverride
+{
+    fn to_tokens(&self, tokens: &mut TkStream) {
+        let method = &self.method;
+        match &self.inherent {
+            Some(inherent) => tokens.extend(quote::quote!(#method = #inherent)),
+            None => tokens.extend(quote::quote!(#method))
+        }
+    }
+}
+
+/// Selection of the trait methods that are forwarded to the component.
+///
+/// Its syntax is `except(method, method = inherent_method, ...)` or `only(method, ..., method = inherent_method, ...)`.
+/// The methods that aren't forwarded are implemented by the partial implementation of the trait declared with
+/// `#[forward_partial]`, or by calling the designated inherent methods of the composite, which have to be named
+/// differently from the trait methods: `Self::method` would resolve to the trait method itself when the composite doesn't declare it.
+#[derive(Clone, Default)]
+pub enum MethodFilter
+{
+    #[default]
+    All,
+    Except(Vec<MethodOverride>),
+    /// forwarded methods, and the overrides of the rest of the methods
+    Only(kw_filter::only, Vec<MethodOverride>)
+}
+
+impl MethodFilter
+{
+    fn entries(&self) -> &[MethodOverride]
+    {
+        match self {
+            Self::All => &[],
+            Self::Except(overrides) | Self::Only(_, overrides) => overrides
+        }
+    }
+
+    /// Checks that the methods named by the filter are declared in the trait, and that the designated inherent methods
+    /// cannot be confused with the methods of the trait
+    pub(crate) fn validate(&self, trait_info:&trait_info_gen::SimpleTraitInfo) -> syn::Result<()>
+    {
+        let is_trait_method = |name:&Ident| trait_info.functions.iter().any(|fsig| fsig.ident == *name);
+        self.entries().iter().try_for_each(|entry| {
+            if !is_trait_method(&entry.method) {
+                return Err(syn::Error::new(entry.method.span(), format!(
+                    "Method `{}` is not declared in trait `{}`.", entry.method, trait_info.ident
+                )));
+            }
+
+            match &entry.inherent {
+                Some(inherent) if is_trait_method(inherent) => Err(syn::Error::new(inherent.span(), format!(
+                    "Method `{}` cannot be implemented by `{}`, which is a method of trait `{}`. The inherent method has to be named differently, as `Self::{}` resolves to the trait method when the type doesn't declare it.",
+                    entry.method, inherent, trait_info.ident, inherent
+                ))),
+                _ => Ok(())
+            }
+        })
+    }
+
+    /// Checks if some excluded methods don't designate an inherent method, so they are left to the partial implementation
+    pub(crate) fn requires_partial_impl(&self, trait_info:&trait_info_gen::SimpleTraitInfo) -> bool
+    {
+        self.excluded_methods(trait_info).iter().any(|method| self.inherent_method(method).is_none())
+    }
+
+    /// Methods of the trait that aren't forwarded
+    pub(crate) fn excluded_methods(&self, trait_info:&trait_info_gen::SimpleTraitInfo) -> Vec<Ident>
+    {
+        match self {
+            Self::All => Vec::new(),
+            Self::Except(overrides) => overrides.iter().map(|method_override| method_override.method.clone()).collect(),
+            Self::Only(_, entries) => trait_info.functions.iter().filter(
+                |fsig| !entries.iter().any(|entry| entry.inherent.is_none() && entry.method == fsig.ident)
+            ).map(|fsig| fsig.ident.clone()).collect()
+        }
+    }
+
+    /// Inherent method of the composite that implements the excluded `method`
+    pub(crate) fn inherent_method(&self, method:&Ident) -> Option<&Ident>
+    {
+        self.entries().iter().find(|entry| entry.method == *method).and_then(|entry| entry.inherent.as_ref())
+    }
+}
+
+impl syn::parse::Parse for MethodFilter
+{
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.is_empty() {
+            return Ok(Self::All);
+        }
+
+        let lookahead = input.lookahead1();
+        if lookahead.peek(kw_filter::except) {
+            let _except = input.parse::<kw_filter::except>()?;
+            let except_content;
+            let _parens = syn::parenthesized!(except_content in input);
+            let overrides = except_content.parse_terminated(MethodOverride::parse, syn::Token![,])?;
+            Ok(Self::Except(overrides.into_iter().collect()))
+        }
+        else if lookahead.peek(kw_filter::only) {
+            let only_token = input.parse::<kw_filter::only>()?;
+            let only_content;
+            let _parens = syn::parenthesized!(only_content in input);
+            let entries = only_content.parse_terminated(MethodOverride::parse, syn::Token![,])?;
+            Ok(Self::Only(only_token, entries.into_iter().collect()))
+        }
+        else {
+            Err(lookahead.error())
+        }
+    }
+}
+
+impl quote::ToTokens for MethodFilter
+{
+    fn to_tokens(&self, tokens: &mut TkStream) {
+        match self {
+            Self::All => {},
+            Self::Except(overrides) => tokens.extend(quote::quote!(except(#(#overrides),*))),
+            Self::Only(only_token, entries) => tokens.extend(quote::quote!(#only_token(#(#entries),*)))
+        }
+    }
+}
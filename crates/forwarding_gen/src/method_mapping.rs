@@ -0,0 +1,184 @@
+/*
+Hereditary
+Autors: Francisco Leon <https://github.com/superoptimo>
+License Apache-2.0
+*/
+
+use proc_macro2::token_stream::TokenStream as TkStream;
+use syn::parse::Parse;
+use syn::{Expr, Ident};
+
+use crate::method_member_adapter::ForwardingMember;
+
+/// Trait method that is forwarded to a differently named method of the component.
+///
+/// Its syntax is `method = component_method`, where the arguments are passed in the same order,
+/// or `method(arg, ...) = component_method(expr, ...)`, where the arguments of the trait method
+/// are bound to the names on the left and the component method is called with the expressions on the right.
+#[derive(Clone)]
+pub struct MethodMapping
+{
+    pub method:Ident,
+    pub params:Option<Vec<Ident>>,
+    pub target:Ident,
+    pub args:Option<Vec<Expr>>
+}
+
+impl Parse for MethodMapping
+{
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let method:Ident = input.parse()?;
+        let params = if input.peek(syn::token::Paren) {
+            let params_content;
+            let _parens = syn::parenthesized!(params_content in input);
+            let params = params_content.parse_terminated(Ident::parse, syn::Token![,])?;
+            Some(params.into_iter().collect())
+        } else {None};
+
+        let _eq = input.parse::<syn::Token![=]>()?;
+        let target:Ident = input.parse()?;
+        let args = if input.peek(syn::token::Paren) {
+            let args_content;
+            let _parens = syn::parenthesized!(args_content in input);
+            let args = args_content.parse_terminated(Expr::parse, syn::Token![,])?;
+            Some(args.into_iter().collect())
+        } else {None};
+
+        Ok(Self{method, params, target, args})
+    }
+}
+
+impl quote::ToTokens for MethodMapping
+{
+    fn to_tokens(&self, tokens: &mut TkStream) {
+        let method = &self.method;
+        let target = &self.target;
+        let params = self.params.as_ref().map(|params| quote::quote!((#(#params),*)));
+        let args = self.args.as_ref().map(|args| quote::quote!((#(#args),*)));
+        tokens.extend(quote::quote!(#method #params = #target #args));
+    }
+}
+
+/// Parses the comma separated list of mappings inside `map(..)`
+pub(crate) fn parse_mappings(input: syn::parse::ParseStream) -> syn::Result<Vec<MethodMapping>>
+{
+    let mappings_content;
+    let _parens = syn::parenthesized!(mappings_content in input);
+    let mappings = mappings_content.parse_terminated(MethodMapping::parse, syn::Token![,])?;
+    Ok(mappings.into_iter().collect())
+}
+
+/// Trait method that is forwarded to another member of the composite, instead of the designated one.
+///
+/// Its syntax is `method = member` or `method = member: Type`.
+#[derive(Clone)]
+pub struct MethodRoute
+{
+    pub method:Ident,
+    pub member:ForwardingMember
+}
+
+impl Parse for MethodRoute
+{
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let method:Ident = input.parse()?;
+        let _eq = input.parse::<syn::Token![=]>()?;
+        let member:ForwardingMember = input.parse()?;
+        Ok(Self{method, member})
+    }
+}
+
+impl quote::ToTokens for MethodRoute
+{
+    fn to_tokens(&self, tokens: &mut TkStream) {
+        let method = &self.method;
+        let member = &self.member;
+        tokens.extend(quote::quote!(#method = #member));
+    }
+}
+
+/// Parses the comma separated list of routes inside `route(..)`
+pub(crate) fn parse_routes(input: syn::parse::ParseStream) -> syn::Result<Vec<MethodRoute>>
+{
+    let routes_content;
+    let _parens = syn::parenthesized!(routes_content in input);
+    let routes = routes_content.parse_terminated(MethodRoute::parse, syn::Token![,])?;
+    Ok(routes.into_iter().collect())
+}
+
+/// Fallback of a trait method when the component is missing, instead of the fallback of the trait.
+///
+/// Its syntax is `method = default`, `method = panic` or `method = path`.
+#[derive(Clone)]
+pub struct MethodFallback
+{
+    pub method:Ident,
+    pub fallback:syn::Path
+}
+
+impl Parse for MethodFallback
+{
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let method:Ident = input.parse()?;
+        let _eq = input.parse::<syn::Token![=]>()?;
+        let fallback:syn::Path = input.parse()?;
+        Ok(Self{method, fallback})
+    }
+}
+
+impl quote::ToTokens for MethodFallback
+{
+    fn to_tokens(&self, tokens: &mut TkStream) {
+        let method = &self.method;
+        let fallback = &self.fallback;
+        tokens.extend(quote::quote!(#method = #fallback));
+    }
+}
+
+/// Parses the comma separated list of fallbacks inside `method_fallback(..)`
+pub(crate) fn parse_method_fallbacks(input: syn::parse::ParseStream) -> syn::Result<Vec<MethodFallback>>
+{
+    let fallbacks_content;
+    let _parens = syn::parenthesized!(fallbacks_content in input);
+    let fallbacks = fallbacks_content.parse_terminated(MethodFallback::parse, syn::Token![,])?;
+    Ok(fallbacks.into_iter().collect())
+}
+
+/// Aggregator of the values returned by a trait method that is broadcast to the elements of a collection.
+///
+/// Its syntax is `method = sum`, `method = min`, `method = max`, `method = all`, `method = any`, `method = collect`
+/// or `method = fold_fn`.
+#[derive(Clone)]
+pub struct MethodAggregate
+{
+    pub method:Ident,
+    pub aggregator:syn::Path
+}
+
+impl Parse for MethodAggregate
+{
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let method:Ident = input.parse()?;
+        let _eq = input.parse::<syn::Token![=]>()?;
+        let aggregator:syn::Path = input.parse()?;
+        Ok(Self{method, aggregator})
+    }
+}
+
+impl quote::ToTokens for MethodAggregate
+{
+    fn to_tokens(&self, tokens: &mut TkStream) {
+        let method = &self.method;
+        let aggregator = &self.aggregator;
+        tokens.extend(quote::quote!(#method = #aggregator));
+    }
+}
+
+/// Parses the comma separated list of aggregators inside `aggregate(..)`
+pub(crate) fn parse_method_aggregates(input: syn::parse::ParseStream) -> syn::Result<Vec<MethodAggregate>>
+{
+    let aggregates_content;
+    let _parens = syn::parenthesized!(aggregates_content in input);
+    let aggregates = aggregates_content.parse_terminated(MethodAggregate::parse, syn::Token![,])?;
+    Ok(aggregates.into_iter().collect())
+}
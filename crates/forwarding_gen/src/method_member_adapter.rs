@@ -8,6 +8,16 @@ use proc_macro2::token_stream::TokenStream as TkStream;
 use quote::ToTokens;
 use syn::{Ident, Signature, Expr};
 use proc_macro2::Span;
+use crate::forwarding_options::ForwardingOptions;
+use crate::method_mapping::MethodMapping;
+use crate::member_accessor::generate_accessor_access;
+use crate::cell_proxy::{CellProxy, peel_cell_type};
+use crate::optional_member::OptionalProxy;
+use crate::lazy_member::{LazyProxy, peel_lazy_type};
+use crate::broadcast_member::BroadcastProxy;
+use crate::chain_member::ChainProxy;
+use crate::swappable_slot::{generate_slot_load, peel_slot_type};
+use crate::self_type_mapping::{is_self_type, type_mentions_self, generate_output_rebuild, generate_input_projection, MemberIndirection};
 use sha3::{Digest, Sha3_256};
 use base32ct::{Base32Unpadded, Encoding};
 
@@ -16,6 +26,7 @@ pub(crate) enum MethodAdaptError
 {
     NotDispatchable(Span),
     MalformedArgument(Span),
+    Unforwardable(Span, String),
     SyntaxError(syn::Error)
 }
 
@@ -23,8 +34,9 @@ impl From<MethodAdaptError> for syn::Error
 {
     fn from(value: MethodAdaptError) -> Self {
         match value {
-            MethodAdaptError::NotDispatchable(s) => syn::Error::new(s, "Method not dispatchable. Object-Safe Traits require a valid receiver."),
+            MethodAdaptError::NotDispatchable(s) => syn::Error::new(s, "Method not dispatchable. It requires a receiver (`self`, `&self`, `&mut self`, `Box<Self>`, `Rc<Self>`, `Arc<Self>`, `Pin<&Self>` or `Pin<&mut Self>`), or the annotated type of the field for associated functions."),
             MethodAdaptError::MalformedArgument(s) => syn::Error::new(s, "Malformed argument in Signature, identifier required."),
+            MethodAdaptError::Unforwardable(s, msg) => syn::Error::new(s, msg),
             MethodAdaptError::SyntaxError(err) => err
         }
     }
@@ -44,14 +56,33 @@ impl std::fmt::Display for MethodAdaptError
     }
 }
 
+/// Gathers the errors of the trait methods that cannot be forwarded into a single diagnostic that lists all of them,
+/// located at `span`.
+pub(crate) fn generate_unforwardable_error(
+    span:Span,
+    trait_name:&Ident,
+    target_desc:&str,
+    failures:Vec<(Ident, MethodAdaptError)>
+) -> syn::Error
+{
+    let items_list:Vec<String> = failures.iter().map(
+        |(method_name, err)| format!("  - `{}`: {}", method_name, err)
+    ).collect();
+
+    syn::Error::new(span, format!(
+        "Cannot forward {} method(s) of trait `{}` to {}:\n{}",
+        failures.len(), trait_name, target_desc, items_list.join("\n")
+    ))
+}
+
 fn generate_macro_signature_hash(
-    submember:&Ident, 
+    target_key:&TkStream,
     method_sig:&Signature,
     base_hash:&str
 ) -> String
 {
     let strbulk = format!("member:{},signature:{},{}", 
-        submember.to_string(),
+        target_key,
         method_sig.to_token_stream(),
         base_hash
     );
@@ -62,45 +93,627 @@ fn generate_macro_signature_hash(
     Base32Unpadded::encode_string(&finv[..])
 }
 
-pub(crate) struct TraitMethodImplMacros
+/// `async` methods await the future of the component, so the composite method keeps the same contract.
+/// Methods that return `impl Future` are forwarded without awaiting, as their signature is preserved.
+fn generate_await(method_sig:&Signature) -> Option<TkStream>
 {
-    pub(crate) macro_decl:TkStream,
-    pub(crate) macro_invoke:TkStream
+    method_sig.asyncness.map(|_| quote::quote!(.await))
 }
 
-impl TraitMethodImplMacros
+/// Generic arguments of the method call, `::<G...>`.
+/// Lifetimes are left to inference, as late bound lifetimes cannot be specified.
+fn generate_turbofish(method_sig:&Signature) -> Option<TkStream>
 {
-    pub(crate) fn create(
-        struct_member: &Ident, 
-        method_sig: &Signature,
-        base_hash:&str
-    ) -> Result<Self, MethodAdaptError>
+    let generic_args:Vec<&Ident> = method_sig.generics.params.iter().filter_map(|param| match param {
+        syn::GenericParam::Type(tparam) => Some(&tparam.ident),
+        syn::GenericParam::Const(cparam) => Some(&cparam.ident),
+        syn::GenericParam::Lifetime(_) => None
+    }).collect();
+    if generic_args.is_empty() {None} else {Some(quote::quote!(::<#(#generic_args),*>))}
+}
+
+/// Call built by `call` from the receiver and the arguments of the method.
+/// The `unsafe` block of an `unsafe fn` only contains the call, as `match (receiver, args) {(r, a) => unsafe { call(r, a) }}`,
+/// so the expressions given by the forwarding options, like accessors or mapped arguments, are evaluated outside of it.
+pub(crate) fn generate_guarded_call(
+    method_sig:&Signature,
+    receiver_arg:Option<TkStream>,
+    arguments:&[TkStream],
+    call:impl FnOnce(Option<TkStream>, &[TkStream]) -> TkStream
+) -> TkStream
+{
+    if method_sig.unsafety.is_none() {
+        return call(receiver_arg, arguments);
+    }
+
+    let receiver_binding = receiver_arg.as_ref().map(|_| quote::quote!(__receiver));
+    let argument_bindings:Vec<TkStream> = (0..arguments.len()).map(
+        |index| quote::format_ident!("__arg{}", index).to_token_stream()
+    ).collect();
+    let unsafe_call = call(receiver_binding.clone(), &argument_bindings);
+
+    let values = receiver_arg.iter().chain(arguments.iter());
+    let bindings = receiver_binding.iter().chain(argument_bindings.iter());
+    quote::quote!(match (#(#values,)*) { (#(#bindings,)*) => unsafe { #unsafe_call } })
+}
+
+/// Fully qualified call of the trait method, `<ComponentTy as Trait>::method::<G...>(receiver, args)`,
+/// so inherent methods or methods of other traits with the same name are never called instead.
+pub(crate) fn generate_trait_call(
+    trait_path:&syn::Path,
+    component_ty:&TkStream,
+    receiver_arg:Option<TkStream>,
+    method_sig:&Signature,
+    parameters:&[TkStream]
+) -> TkStream
+{
+    let method_name = &method_sig.ident;
+    let await_call = generate_await(method_sig);
+    let turbofish = generate_turbofish(method_sig);
+
+    generate_guarded_call(method_sig, receiver_arg, parameters, |receiver_arg, parameters| {
+        let arguments = receiver_arg.iter().chain(parameters.iter());
+        quote::quote!(<#component_ty as #trait_path>::#method_name #turbofish ( #(#arguments),* ) #await_call)
+    })
+}
+
+/// Call of the trait method on the component passed as `receiver_arg`. Components whose type is unknown use the
+/// method call syntax, `(receiver).method::<G...>(args)`, as its auto-deref reaches the components stored behind
+/// `Box`, references or other pointers, which the inference of `<_ as Trait>` cannot do.
+pub(crate) fn generate_component_call(
+    trait_path:&syn::Path,
+    component_ty:Option<&TkStream>,
+    receiver_arg:TkStream,
+    method_sig:&Signature,
+    parameters:&[TkStream]
+) -> TkStream
+{
+    if let Some(component_ty) = component_ty {
+        return generate_trait_call(trait_path, component_ty, Some(receiver_arg), method_sig, parameters);
+    }
+
+    let method_name = &method_sig.ident;
+    let await_call = generate_await(method_sig);
+    let turbofish = generate_turbofish(method_sig);
+    generate_guarded_call(method_sig, Some(receiver_arg), parameters, |receiver_arg, parameters| {
+        quote::quote!((#receiver_arg).#method_name #turbofish ( #(#parameters),* ) #await_call)
+    })
+}
+
+/// Type of a component reached through the cell, option or lazy initializer of the submember.
+/// Components stored behind a reference, `Box`, `Rc` or `Arc` are left to the method call syntax, which dereferences them.
+pub(crate) fn generate_proxied_component_type(component_ty:Option<&syn::Type>) -> Option<TkStream>
+{
+    match component_ty? {
+        syn::Type::Reference(_) => None,
+        pointer_ty if smart_pointer_parts(pointer_ty).is_some_and(|(pointer_name, _)| ["Box", "Rc", "Arc"].iter().any(|name| pointer_name == name)) => None,
+        component_ty => Some(component_ty.to_token_stream())
+    }
+}
+
+/// Struct member that receives the forwarded methods, with its declared type if known.
+///
+/// Its syntax is `member` or `member: Type`, where `member` is a field name or a tuple index.
+#[derive(Clone)]
+pub struct ForwardingMember
+{
+    pub member:syn::Member,
+    pub member_ty:Option<syn::Type>
+}
+
+impl ForwardingMember
+{
+    /// Name of the member for diagnostics, the field name or its tuple index.
+    pub fn member_name(&self) -> String
+    {
+        match &self.member {
+            syn::Member::Named(ident) => ident.to_string(),
+            syn::Member::Unnamed(index) => index.index.to_string()
+        }
+    }
+
+    pub fn member_span(&self) -> Span
+    {
+        match &self.member {
+            syn::Member::Named(ident) => ident.span(),
+            syn::Member::Unnamed(index) => index.span
+        }
+    }
+}
+
+impl syn::parse::Parse for ForwardingMember
+{
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let member:syn::Member = input.parse()?;
+        let member_ty = if input.peek(syn::token::Colon) {
+            let _colon = input.parse::<syn::token::Colon>()?;
+            Some(input.parse::<syn::Type>()?)
+        }
+        else {None};
+
+        Ok(Self{member, member_ty})
+    }
+}
+
+impl quote::ToTokens for ForwardingMember
+{
+    fn to_tokens(&self, tokens: &mut TkStream) {
+        let member = &self.member;
+        tokens.extend(quote::quote!(#member));
+        if let Some(member_ty) = &self.member_ty {
+            tokens.extend(quote::quote!(: #member_ty));
+        }
+    }
+}
+
+/// Kinds of method receivers that can be forwarded to a struct member
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ReceiverKind
+{
+    /// `&self`
+    Ref,
+    /// `&mut self`
+    RefMut,
+    /// `self` or `mut self`, the member is moved out of the composite
+    Value,
+    /// `self: Box<Self>`, the member is moved out of the box and re-boxed
+    Boxed,
+    /// `self: Rc<Self>`, the member has to be a `Rc` or being projected
+    Rc,
+    /// `self: Arc<Self>`, the member has to be an `Arc` or being projected
+    Arc,
+    /// `self: Pin<&Self>`, pinned projection of an `Unpin` member, or of the pointee of a `Pin<Box<T>>` member
+    PinRef,
+    /// `self: Pin<&mut Self>`, pinned projection of an `Unpin` member, or of the pointee of a `Pin<Box<T>>` member, on an `Unpin` composite
+    PinMut
+}
+
+impl ReceiverKind
+{
+    fn from_receiver(rcv:&syn::Receiver) -> Option<Self>
+    {
+        if let Some((_, _)) = &rcv.reference {
+            return Some(if rcv.mutability.is_some() {Self::RefMut} else {Self::Ref});
+        }
+
+        match rcv.ty.as_ref() {
+            syn::Type::Reference(tref) if is_self_type(&tref.elem) => {
+                Some(if tref.mutability.is_some() {Self::RefMut} else {Self::Ref})
+            },
+            selfty if is_self_type(selfty) => Some(Self::Value),
+            wrapped => {
+                let (pointer_name, pointee) = smart_pointer_parts(wrapped)?;
+                match (pointer_name.to_string().as_str(), pointee) {
+                    ("Box", selfty) if is_self_type(selfty) => Some(Self::Boxed),
+                    ("Rc", selfty) if is_self_type(selfty) => Some(Self::Rc),
+                    ("Arc", selfty) if is_self_type(selfty) => Some(Self::Arc),
+                    ("Pin", syn::Type::Reference(tref)) if is_self_type(&tref.elem) => {
+                        Some(if tref.mutability.is_some() {Self::PinMut} else {Self::PinRef})
+                    },
+                    _ => None
+                }
+            }
+        }
+    }
+
+    /// Pointee type of submembers stored behind a pointer, that are dereferenced for calling the trait method
+    /// on the pointee. Borrowing receivers dereference references, `Box`, `Rc` and `Arc`, while by value
+    /// receivers only move out of a `Box`. Pinned receivers, and `&self`, reach the pointee of a `Pin<Box<T>>`.
+    pub(crate) fn peeled_member_type<'a>(&self, submember:&'a ForwardingMember) -> Option<&'a syn::Type>
     {
+        let member_ty = submember.member_ty.as_ref()?;
+        if matches!(self, Self::Ref | Self::PinRef | Self::PinMut) {
+            if let Some(pointee) = pinned_box_pointee(member_ty) {
+                return Some(pointee);
+            }
+        }
 
-        let mut params_iter = method_sig.inputs.iter();
+        match (self, member_ty) {
+            (Self::Ref | Self::RefMut, syn::Type::Reference(tref)) => Some(tref.elem.as_ref()),
+            (Self::Ref | Self::RefMut, pointer_ty) => match smart_pointer_parts(pointer_ty) {
+                Some((pointer_name, pointee)) if pointer_name == "Box" || pointer_name == "Rc" || pointer_name == "Arc" => Some(pointee),
+                _ => None
+            },
+            (Self::Value, pointer_ty) => match smart_pointer_parts(pointer_ty) {
+                Some((pointer_name, pointee)) if pointer_name == "Box" => Some(pointee),
+                _ => None
+            },
+            _ => None
+        }
+    }
 
-        // obtain first parameter
-        let first_param = params_iter.next().ok_or(
-            MethodAdaptError::NotDispatchable(method_sig.ident.span())
+    /// How the component peeled from the submember is stored, so the submembers of other instances are projected into it
+    pub(crate) fn member_indirection(&self, submember:&ForwardingMember) -> MemberIndirection
+    {
+        let (Some(_), Some(member_ty)) = (self.peeled_member_type(submember), submember.member_ty.as_ref()) else {
+            return MemberIndirection::Direct;
+        };
+
+        match member_ty {
+            syn::Type::Reference(tref) if tref.mutability.is_some() => MemberIndirection::Unique,
+            pointer_ty if pinned_box_pointee(pointer_ty).is_none() && smart_pointer_parts(pointer_ty).is_some_and(
+                |(pointer_name, _)| pointer_name == "Box"
+            ) => MemberIndirection::Owned,
+            _ => MemberIndirection::Shared
+        }
+    }
+
+    /// Type that implements the trait for the submember, used as `<ComponentTy as Trait>::method`.
+    /// Members stored behind a pointer are peeled to their pointee. It's `None` when the type of the submember is unknown,
+    /// so the trait method is called with the method call syntax.
+    pub(crate) fn generate_component_type(&self, submember:&ForwardingMember, options:&ForwardingOptions) -> Option<TkStream>
+    {
+        if let Some(pointee) = self.peeled_member_type(submember) {
+            return Some(pointee.to_token_stream());
+        }
+
+        let member_ty = submember.member_ty.as_ref()?;
+        let projection = match self {
+            Self::Rc => &options.rc_project,
+            Self::Arc => &options.arc_project,
+            _ => { return Some(member_ty.to_token_stream()); }
+        };
+
+        // shared receivers take the pointee of the shared member
+        match (projection, smart_pointer_parts(member_ty)) {
+            (None, Some((_, pointee))) => Some(pointee.to_token_stream()),
+            _ => None
+        }
+    }
+
+    /// Expression that obtains the submember in the form required by the receiver,
+    /// which is passed as the first argument of the trait method.
+    pub(crate) fn generate_member_access(
+        &self,
+        submember:&ForwardingMember,
+        options:&ForwardingOptions,
+        method_name:&Ident
+    ) -> Result<TkStream, MethodAdaptError>
+    {
+        let member = &submember.member;
+        let deref = self.peeled_member_type(submember).map(|_| quote::quote!(*));
+        match self {
+            Self::Ref => Ok(quote::quote!(&#deref $self_token.#member)),
+            Self::RefMut => Ok(quote::quote!(&mut #deref $self_token.#member)),
+            Self::Value => Ok(quote::quote!(#deref $self_token.#member)),
+            Self::Boxed => Ok(quote::quote!(::std::boxed::Box::new((*$self_token).#member))),
+            Self::Rc => Self::generate_shared_access(
+                submember, &options.rc_project, method_name,
+                "Rc", crate::forwarding_options::FORWARD_OPTION_RC_PROJECT,
+                quote::quote!(::std::rc::Rc)
+            ),
+            Self::Arc => Self::generate_shared_access(
+                submember, &options.arc_project, method_name,
+                "Arc", crate::forwarding_options::FORWARD_OPTION_ARC_PROJECT,
+                quote::quote!(::std::sync::Arc)
+            ),
+            // Safe structural projection: pinning the member only requires it to be `Unpin`,
+            // and unwrapping a mutable pinned composite also requires the composite to be `Unpin`.
+            // Components that aren't `Unpin` are stored as `Pin<Box<T>>`, which is `Unpin` and reborrows the pinned pointee.
+            Self::PinRef if deref.is_some() => Ok(quote::quote!(
+                ::core::pin::Pin::as_ref(&::core::pin::Pin::get_ref($self_token).#member)
+            )),
+            Self::PinMut if deref.is_some() => Ok(quote::quote!(
+                ::core::pin::Pin::as_mut(&mut ::core::pin::Pin::into_inner($self_token).#member)
+            )),
+            Self::PinRef => Ok(quote::quote!(
+                ::core::pin::Pin::new(&::core::pin::Pin::get_ref($self_token).#member)
+            )),
+            Self::PinMut => Ok(quote::quote!(
+                ::core::pin::Pin::new(&mut ::core::pin::Pin::into_inner($self_token).#member)
+            ))
+        }
+    }
+
+    /// Receivers that are moved into the member access, so the composite isn't available after the call.
+    fn consumes_receiver(&self) -> bool
+    {
+        matches!(self, Self::Value | Self::Boxed | Self::Rc | Self::Arc | Self::PinMut)
+    }
+
+    /// Expression that borrows the composite from the receiver, as `&Self`.
+    fn generate_composite_ref(&self) -> TkStream
+    {
+        match self {
+            Self::Value => quote::quote!(&$self_token),
+            _ => quote::quote!(&*$self_token)
+        }
+    }
+
+    /// Shared pointers cannot move the member out of the composite, so they require
+    /// that the member was already stored as a shared pointer, or an explicit projection function.
+    fn generate_shared_access(
+        submember:&ForwardingMember,
+        projection:&Option<syn::Path>,
+        method_name:&Ident,
+        pointer_name:&str,
+        option_name:&str,
+        pointer_path:TkStream
+    ) -> Result<TkStream, MethodAdaptError>
+    {
+        let member = &submember.member;
+        if let Some(project_fn) = projection {
+            return Ok(quote::quote!(#project_fn($self_token)));
+        }
+
+        match &submember.member_ty {
+            Some(member_ty) if smart_pointer_parts(member_ty).is_none_or(|(pname, _)| pname != pointer_name) => {
+                Err(MethodAdaptError::Unforwardable(method_name.span(), format!(
+                    "Method `{}` takes `self: {}<Self>`, so the member `{}` has to be declared as `{}<T>`, or a projection function has to be provided with `{} = path`.",
+                    method_name, pointer_name, submember.member_name(), pointer_name, option_name
+                )))
+            },
+            _ => Ok(quote::quote!(#pointer_path::clone(&$self_token.#member)))
+        }
+    }
+}
+
+/// Obtains the name and the pointee of a generic pointer type like `Box<T>` or `std::rc::Rc<T>`.
+pub(crate) fn smart_pointer_parts(ty:&syn::Type) -> Option<(&Ident, &syn::Type)>
+{
+    if let syn::Type::Path(tpath) = ty {
+        let last = tpath.path.segments.last()?;
+        match &last.arguments {
+            syn::PathArguments::AngleBracketed(args) if args.args.len() == 1 => {
+                match args.args.first()? {
+                    syn::GenericArgument::Type(pointee) => Some((&last.ident, pointee)),
+                    _ => None
+                }
+            },
+            _ => None
+        }
+    }
+    else {None}
+}
+
+/// Obtains the pointee of a pinned box type, like `T` in `Pin<Box<T>>`.
+fn pinned_box_pointee(ty:&syn::Type) -> Option<&syn::Type>
+{
+    let (pin_name, pointer_ty) = smart_pointer_parts(ty)?;
+    let (box_name, pointee) = smart_pointer_parts(pointer_ty)?;
+    if pin_name == "Pin" && box_name == "Box" {Some(pointee)} else {None}
+}
+
+/// Information shared by all the forwarded methods of a trait implementation
+pub(crate) struct MethodForwardingContext<'a>
+{
+    pub(crate) submember:&'a ForwardingMember,
+    pub(crate) trait_path:&'a syn::Path,
+    pub(crate) options:&'a ForwardingOptions
+}
+
+impl MethodForwardingContext<'_>
+{
+    /// Description of the component for diagnostics and documentation
+    pub(crate) fn describe_target(&self) -> String
+    {
+        match self.options.first_accessor() {
+            Some(accessor) => format!("the component `{}`", accessor.to_token_stream().to_string().replace(' ', "")),
+            None => format!("the field `{}`", self.submember.member_name())
+        }
+    }
+
+    /// Builds an instance of the composite from a new instance of the component,
+    /// following the `construct` option.
+    fn generate_construct(&self, component_value:TkStream) -> Option<TkStream>
+    {
+        let construct_path = self.options.construct.as_ref()?;
+        let member = &self.submember.member;
+        if construct_path.is_ident(crate::forwarding_options::FORWARD_CONSTRUCT_DEFAULT) {
+            Some(quote::quote!({
+                let mut __composite:Self = ::core::default::Default::default();
+                __composite.#member = #component_value;
+                __composite
+            }))
+        }
+        else {
+            Some(quote::quote!(#construct_path(#component_value)))
+        }
+    }
+
+    /// Builds an instance of the composite from the value returned by the component,
+    /// following the `rewrap` option. `composite_ref` borrows the original composite.
+    fn generate_rewrap(&self, composite_ref:&TkStream, component_value:TkStream) -> Option<TkStream>
+    {
+        let rewrap_path = self.options.rewrap.as_ref()?;
+        let member = &self.submember.member;
+        if rewrap_path.is_ident(crate::forwarding_options::FORWARD_REWRAP_CLONE) {
+            Some(quote::quote!({
+                let mut __composite:Self = ::core::clone::Clone::clone(#composite_ref);
+                __composite.#member = #component_value;
+                __composite
+            }))
+        }
+        else {
+            Some(quote::quote!(#rewrap_path(#composite_ref, #component_value)))
+        }
+    }
+
+    fn generate_trait_call(
+        &self,
+        component_ty:&TkStream,
+        receiver_arg:Option<TkStream>,
+        method_sig:&Signature,
+        parameters:&[TkStream]
+    ) -> TkStream
+    {
+        generate_trait_call(self.trait_path, component_ty, receiver_arg, method_sig, parameters)
+    }
+
+    fn generate_component_call(
+        &self,
+        component_ty:Option<&TkStream>,
+        receiver_arg:TkStream,
+        method_sig:&Signature,
+        parameters:&[TkStream]
+    ) -> TkStream
+    {
+        generate_component_call(self.trait_path, component_ty, receiver_arg, method_sig, parameters)
+    }
+
+    /// Arguments of a method forwarded to a component that is reached through an accessor, a proxy or a collection,
+    /// which cannot take or return `Self`, as the composite cannot be rebuilt from such component.
+    /// `target_desc` names the component in the diagnostic.
+    fn generate_proxied_parameters(
+        &self,
+        method_sig:&Signature,
+        signature_parts:&MethodSignatureParts,
+        target_desc:&str
+    ) -> Result<Vec<TkStream>, MethodAdaptError>
+    {
+        let mentions_self = signature_parts.parameter_types().any(type_mentions_self) ||
+            matches!(&method_sig.output, syn::ReturnType::Type(_, output_ty) if type_mentions_self(output_ty));
+        if mentions_self {
+            return Err(MethodAdaptError::Unforwardable(method_sig.ident.span(), format!(
+                "Method `{}` takes or returns `Self`, which cannot be forwarded to {}.", method_sig.ident, target_desc
+            )));
+        }
+
+        Ok(signature_parts.parameter_ids.iter().map(|param_id| param_id.to_token_stream()).collect())
+    }
+
+    /// Converts the value returned by the method of the component into the output of the composite,
+    /// when the output mentions `Self`.
+    fn generate_receiver_output(
+        &self,
+        method_sig:&Signature,
+        receiver_kind:ReceiverKind,
+        component_ty:Option<&TkStream>,
+        member_call:TkStream,
+        parameters:&[TkStream]
+    ) -> Result<TkStream, MethodAdaptError>
+    {
+        let method_name = &method_sig.ident;
+        let output_ty = match &method_sig.output {
+            syn::ReturnType::Type(_, output_ty) if type_mentions_self(output_ty) => output_ty,
+            _ => { return Ok(member_call); }
+        };
+
+        let member = &self.submember.member;
+
+        // Methods that consume the composite and return a new one, put the result back into the composite.
+        if is_self_type(output_ty) && self.options.rewrap.is_none() {
+            match receiver_kind {
+                ReceiverKind::Value => {
+                    // boxed members are moved out of the box and boxed again
+                    let (member_arg, member_rebox) = match receiver_kind.peeled_member_type(self.submember) {
+                        Some(_) => (quote::quote!(*__composite.#member), Some(quote::quote!(::std::boxed::Box::new))),
+                        None => (quote::quote!(__composite.#member), None)
+                    };
+                    let reinsert_call = self.generate_component_call(component_ty, member_arg, method_sig, parameters);
+                    return Ok(quote::quote!({
+                        let mut __composite = $self_token;
+                        __composite.#member = #member_rebox(#reinsert_call);
+                        __composite
+                    }));
+                },
+                ReceiverKind::Boxed => {
+                    let reinsert_call = self.generate_component_call(
+                        component_ty, quote::quote!(::std::boxed::Box::new(__composite.#member)), method_sig, parameters
+                    );
+                    return Ok(quote::quote!({
+                        let mut __composite = *$self_token;
+                        __composite.#member = #reinsert_call;
+                        __composite
+                    }));
+                },
+                _ => {}
+            }
+        }
+
+        if self.options.rewrap.is_none() {
+            return Err(MethodAdaptError::Unforwardable(method_name.span(), format!(
+                "Method `{}` returns `Self`, so it requires a strategy for rebuilding the composite from the member `{}`: `rewrap = clone` or `rewrap = path`.",
+                method_name, self.submember.member_name()
+            )));
+        }
+
+        // The composite is moved by the call, so the rest of the fields are taken from a copy.
+        let (template_decl, composite_ref) = if receiver_kind.consumes_receiver() {
+            let composite_ref = receiver_kind.generate_composite_ref();
+            (
+                Some(quote::quote!(let __template:Self = ::core::clone::Clone::clone(#composite_ref);)),
+                quote::quote!(&__template)
+            )
+        }
+        else {
+            (None, receiver_kind.generate_composite_ref())
+        };
+
+        let output_rebuild = generate_output_rebuild(
+            output_ty, member_call, &|component_value| self.generate_rewrap(&composite_ref, component_value).unwrap_or_default()
+        ).ok_or(MethodAdaptError::Unforwardable(method_name.span(), format!(
+            "Method `{}` returns `Self` in a form that cannot be rebuilt from the member `{}`.",
+            method_name, self.submember.member_name()
+        )))?;
+
+        Ok(quote::quote!({
+            #template_decl
+            #output_rebuild
+        }))
+    }
+
+    /// Associated functions without receiver are forwarded by calling the function
+    /// implemented by the type of the submember, so they require that the type is known.
+    fn generate_static_call(
+        &self,
+        method_sig:&Signature,
+        parameters:&[TkStream]
+    ) -> Result<TkStream, MethodAdaptError>
+    {
+        let method_name = &method_sig.ident;
+        let member_ty = self.submember.member_ty.as_ref().ok_or(
+            MethodAdaptError::NotDispatchable(method_name.span())
         )?;
+        let static_call = self.generate_trait_call(&member_ty.to_token_stream(), None, method_sig, parameters);
+
+        let output_ty = match &method_sig.output {
+            syn::ReturnType::Type(_, output_ty) if type_mentions_self(output_ty) => output_ty,
+            _ => { return Ok(static_call); }
+        };
+
+        if self.options.construct.is_none() {
+            return Err(MethodAdaptError::Unforwardable(method_name.span(), format!(
+                "Function `{}` returns `Self`, so it requires a constructor strategy for building the composite from the member `{}`: `construct = default` or `construct = path`.",
+                method_name, self.submember.member_name()
+            )));
+        }
+
+        generate_output_rebuild(
+            output_ty, static_call, &|component_value| self.generate_construct(component_value).unwrap_or_default()
+        ).ok_or(MethodAdaptError::Unforwardable(method_name.span(), format!(
+            "Function `{}` returns `Self` in a form that cannot be built from the member `{}`.",
+            method_name, self.submember.member_name()
+        )))
+    }
+}
+
+/// Receiver and arguments of a trait method signature
+pub(crate) struct MethodSignatureParts<'a>
+{
+    pub(crate) receiver:Option<(&'a syn::Receiver, ReceiverKind)>,
+    pub(crate) parameter_ids:Vec<Expr>,
+    pub(crate) signature_params:Vec<syn::FnArg>
+}
+
+impl<'a> MethodSignatureParts<'a>
+{
+    pub(crate) fn extract(method_sig:&'a Signature) -> Result<Self, MethodAdaptError>
+    {
+        let mut params_iter = method_sig.inputs.iter().peekable();
 
         // check if first parameter is a valid receiver
-        let receiver_pat:TkStream = match first_param {
-            syn::FnArg::Receiver(rcv) => {
-                if rcv.reference.is_some() {
-                    let muttk = rcv.mutability;
-                    let stks = quote::quote!(&#muttk);
-                    Ok(stks)
-                }
-                else
-                {
-                    Err(MethodAdaptError::NotDispatchable(method_sig.ident.span()))
-                }                
+        let receiver = match params_iter.peek() {
+            Some(syn::FnArg::Receiver(rcv)) => {
+                let receiver_kind = ReceiverKind::from_receiver(rcv).ok_or(
+                    MethodAdaptError::NotDispatchable(method_sig.ident.span())
+                )?;
+                params_iter.next();
+                Some((rcv, receiver_kind))
             },
-            _ => Err(MethodAdaptError::NotDispatchable(method_sig.ident.span()))
-        }?;
+            _ => None
+        };
 
-        
         // extract the rest of parameters names
         let parameters_pair: Vec<(Expr, syn::FnArg)> = params_iter.map(
             |arg| -> Result<(Expr, syn::FnArg), MethodAdaptError> {
@@ -114,7 +727,7 @@ impl TraitMethodImplMacros
                         match innerpat {
                             syn::Pat::Ident(idx) => {
                                 let ret_expr= syn::parse2::<Expr>(idx.ident.to_token_stream());
-                                ret_expr.map(|expr | (expr, arg.clone() ) ).or_else(|err| Err(err.into()))                            
+                                ret_expr.map(|expr | (expr, arg.clone() ) ).or_else(|err| Err(err.into()))
                             },
                             _ => {
                                 Err(MethodAdaptError::MalformedArgument(method_sig.ident.span()))
@@ -125,10 +738,634 @@ impl TraitMethodImplMacros
             }
         ).collect::<Result< _ , _> >()?;
 
-        let (parameters, signature_params) : (Vec<Expr>, Vec<syn::FnArg>) = parameters_pair.into_iter().unzip();
+        let (parameter_ids, signature_params) : (Vec<Expr>, Vec<syn::FnArg>) = parameters_pair.into_iter().unzip();
+
+        Ok(Self{receiver, parameter_ids, signature_params})
+    }
+
+    /// Types of the arguments after the receiver
+    pub(crate) fn parameter_types(&self) -> impl Iterator<Item = &syn::Type>
+    {
+        self.signature_params.iter().filter_map(|param| match param {
+            syn::FnArg::Typed(typed) => Some(typed.ty.as_ref()),
+            syn::FnArg::Receiver(_) => None
+        })
+    }
+
+    /// Receiver type with its explicit form (`self: &'a Self`).
+    /// By value receivers don't need `mut`, because the member is moved out.
+    fn generate_receiver_decl(&self) -> Option<TkStream>
+    {
+        self.receiver.map(|(receiver, _)| {
+            let receiver_ty = &receiver.ty;
+            quote::quote!($self_token: #receiver_ty,)
+        })
+    }
+}
+
+pub(crate) struct TraitMethodImplMacros
+{
+    pub(crate) macro_decl:TkStream,
+    pub(crate) macro_invoke:TkStream
+}
+
+impl TraitMethodImplMacros
+{
+    pub(crate) fn create(
+        context: &MethodForwardingContext,
+        method_sig: &Signature,
+        base_hash:&str
+    ) -> Result<Self, MethodAdaptError>
+    {
+        // routed methods are forwarded to another member
+        let route = context.options.find_route(&method_sig.ident);
+        let context = &MethodForwardingContext{
+            submember:route.map_or(context.submember, |route| &route.member),
+            trait_path:context.trait_path,
+            options:context.options
+        };
+
+        // accessors reach the component of the methods that aren't routed
+        let through_accessors = route.is_none() && context.options.has_accessors();
+
+        if let Some(mapping) = context.options.find_mapping(&method_sig.ident) {
+            return Self::create_mapped_call(context, method_sig, mapping, through_accessors, base_hash);
+        }
+
+        // the routed members only implement the routed methods, which are called with method call syntax
+        if route.is_some() {
+            let method_name = &method_sig.ident;
+            let mapping = MethodMapping{method:method_name.clone(), params:None, target:method_name.clone(), args:None};
+            return Self::create_mapped_call(context, method_sig, &mapping, false, base_hash);
+        }
+
+        if through_accessors {
+            return Self::create_accessor_call(context, method_sig, base_hash);
+        }
+
+        // collections, lazy and optional members and cells wrap the designated member only
+        if let Some(broadcast_proxy) = context.options.broadcast_proxy() {
+            return Self::create_broadcast_call(context, method_sig, &broadcast_proxy, base_hash);
+        }
+
+        // swappable components in an `ArcSlot` are called through a snapshot
+        let slot_component_ty = context.submember.member_ty.as_ref().and_then(peel_slot_type).filter(
+            |_| context.options.swappable.is_some()
+        );
+        if let Some(slot_component_ty) = slot_component_ty {
+            return Self::create_slot_call(context, method_sig, slot_component_ty, base_hash);
+        }
+
+        // chained methods that return `Option`, `Result` or `bool`, the rest of the methods are forwarded to the designated member
+        if let Some(chain_proxy) = context.options.chain_proxy() {
+            if let Some(chained_call) = Self::create_chained_call(context, method_sig, &chain_proxy, base_hash)? {
+                return Ok(chained_call);
+            }
+        }
+
+        if let Some(lazy_proxy) = context.options.lazy_proxy() {
+            return Self::create_lazy_call(context, method_sig, &lazy_proxy, base_hash);
+        }
+
+        if let Some(optional_proxy) = context.options.optional_proxy() {
+            return Self::create_optional_call(context, method_sig, &optional_proxy, base_hash);
+        }
+
+        if let Some(cell_proxy) = context.options.cell_proxy() {
+            return Self::create_cell_call(context, method_sig, &cell_proxy, base_hash);
+        }
+
+        let struct_member = context.submember;
+        let signature_parts = MethodSignatureParts::extract(method_sig)?;
+        let method_name = &method_sig.ident;
+
+        // Arguments of the composite type are projected into the submember of the other instance,
+        // which is peeled like the submember of the receiver
+        let indirection = signature_parts.receiver.map_or(
+            MemberIndirection::Direct, |(_, receiver_kind)| receiver_kind.member_indirection(struct_member)
+        );
+        let parameters = signature_parts.parameter_ids.iter().zip(signature_parts.parameter_types()).map(
+            |(param_id, param_ty)| -> Result<TkStream, MethodAdaptError> {
+                generate_input_projection(param_ty, param_id.to_token_stream(), &struct_member.member, indirection).map_err(
+                    |reason| MethodAdaptError::Unforwardable(method_name.span(), format!(
+                        "Argument `{}` of method `{}` cannot be projected into the member `{}`, as {}.",
+                        param_id.to_token_stream(), method_name, struct_member.member_name(), reason
+                    ))
+                )
+            }
+        ).collect::<Result<Vec<TkStream>, _>>()?;
+
+        let forward_call = match signature_parts.receiver {
+            Some((_, receiver_kind)) => {
+                let member_access = receiver_kind.generate_member_access(struct_member, context.options, method_name)?;
+                let component_ty = receiver_kind.generate_component_type(struct_member, context.options);
+                let member_call = context.generate_component_call(component_ty.as_ref(), member_access, method_sig, &parameters);
+                context.generate_receiver_output(method_sig, receiver_kind, component_ty.as_ref(), member_call, &parameters)?
+            },
+            None => context.generate_static_call(method_sig, &parameters)?
+        };
+
+        Ok(Self::assemble(
+            method_sig,
+            &signature_parts,
+            forward_call,
+            &format!("the field `{}`", struct_member.member_name()),
+            context.trait_path,
+            &struct_member.to_token_stream(),
+            base_hash
+        ))
+    }
+
+    /// Implements a method mapped to the differently named method `mapping.target` of the submember,
+    /// as `self.member.target(args)`, or `<MemberTy>::target(args)` for associated functions.
+    /// Routed methods are called the same way, so the routed member doesn't need implementing the trait.
+    /// The arguments of the trait method are bound to the parameter names of the mapping, so the mapped
+    /// arguments can be arbitrary expressions of them.
+    fn create_mapped_call(
+        context: &MethodForwardingContext,
+        method_sig: &Signature,
+        mapping: &MethodMapping,
+        through_accessors: bool,
+        base_hash:&str
+    ) -> Result<Self, MethodAdaptError>
+    {
+        let struct_member = context.submember;
+        let signature_parts = MethodSignatureParts::extract(method_sig)?;
+        let method_name = &method_sig.ident;
+        let target = &mapping.target;
+        let action = if context.options.find_mapping(method_name).is_some() {"mapped"} else {"routed"};
+
+        let params_bindings = match &mapping.params {
+            Some(params) if params.len() != signature_parts.parameter_ids.len() => {
+                return Err(MethodAdaptError::Unforwardable(method_name.span(), format!(
+                    "Method `{}` takes {} arguments, but its mapping names {}.",
+                    method_name, signature_parts.parameter_ids.len(), params.len()
+                )));
+            },
+            Some(params) => {
+                let parameter_ids = &signature_parts.parameter_ids;
+                Some(quote::quote!(#(let #params = #parameter_ids;)*))
+            },
+            None => None
+        };
+
+        let arguments:Vec<TkStream> = match (&mapping.args, &mapping.params) {
+            (Some(args), _) => args.iter().map(|arg| arg.to_token_stream()).collect(),
+            (None, Some(params)) => params.iter().map(|param| param.to_token_stream()).collect(),
+            (None, None) => signature_parts.parameter_ids.iter().map(|param_id| param_id.to_token_stream()).collect()
+        };
 
+        if let syn::ReturnType::Type(_, output_ty) = &method_sig.output {
+            if type_mentions_self(output_ty) {
+                return Err(MethodAdaptError::Unforwardable(method_name.span(), format!(
+                    "Method `{}` returns `Self`, so it cannot be {} to the method `{}` of the member `{}`.",
+                    method_name, action, target, struct_member.member_name()
+                )));
+            }
+        }
+
+        let member = &struct_member.member;
+        let await_call = generate_await(method_sig);
+        let target_call = |receiver_arg:Option<TkStream>, arguments:&[TkStream]| -> TkStream {
+            quote::quote!(#receiver_arg.#target( #(#arguments),* ) #await_call)
+        };
+        let mapped_call = match signature_parts.receiver {
+            // method call syntax takes care of dereferencing the member
+            Some((_, receiver_kind @ (ReceiverKind::Ref | ReceiverKind::RefMut | ReceiverKind::Value))) if through_accessors => {
+                let accessor = Self::find_method_accessor(context.options, method_name, receiver_kind)?;
+                let component = generate_accessor_access(accessor, receiver_kind);
+                generate_guarded_call(method_sig, Some(component), &arguments, target_call)
+            },
+            Some((_, receiver_kind @ (ReceiverKind::Ref | ReceiverKind::RefMut | ReceiverKind::Value))) => {
+                let component = match receiver_kind {
+                    ReceiverKind::Ref => quote::quote!((&$self_token.#member)),
+                    ReceiverKind::RefMut => quote::quote!((&mut $self_token.#member)),
+                    _ => quote::quote!($self_token.#member)
+                };
+                generate_guarded_call(method_sig, Some(component), &arguments, target_call)
+            },
+            Some(_) => {
+                return Err(MethodAdaptError::Unforwardable(method_name.span(), format!(
+                    "Method `{}` cannot be {}, as it requires a `self`, `&self` or `&mut self` receiver.",
+                    method_name, action
+                )));
+            },
+            None => {
+                let member_ty = struct_member.member_ty.as_ref().ok_or_else(|| MethodAdaptError::Unforwardable(method_name.span(), format!(
+                    "Associated function `{}` cannot be {}, as the type of the member `{}` isn't declared.",
+                    method_name, action, struct_member.member_name()
+                )))?;
+                generate_guarded_call(method_sig, None, &arguments, |_, arguments| {
+                    quote::quote!(<#member_ty>::#target( #(#arguments),* ) #await_call)
+                })
+            }
+        };
+
+        Ok(Self::assemble(
+            method_sig,
+            &signature_parts,
+            quote::quote!({
+                #params_bindings
+                #mapped_call
+            }),
+            &format!("the method `{}` of the field `{}`", target, struct_member.member_name()),
+            context.trait_path,
+            &quote::quote!(#struct_member map #mapping),
+            base_hash
+        ))
+    }
+
+    /// Accessor declared for the receiver of the method, `via_ref`/`via_mut` or `via`
+    fn find_method_accessor<'a>(
+        options: &'a ForwardingOptions,
+        method_name: &Ident,
+        receiver_kind: ReceiverKind
+    ) -> Result<&'a syn::Expr, MethodAdaptError>
+    {
+        options.find_accessor(receiver_kind).ok_or_else(|| {
+            let (receiver_desc, specific_key) = match receiver_kind {
+                ReceiverKind::Ref => ("&self", Some(crate::forwarding_options::FORWARD_OPTION_VIA_REF)),
+                ReceiverKind::RefMut => ("&mut self", Some(crate::forwarding_options::FORWARD_OPTION_VIA_MUT)),
+                _ => ("self", None)
+            };
+            let keys = match specific_key {
+                Some(specific_key) => format!("`{}` or `{}`", crate::forwarding_options::FORWARD_OPTION_VIA, specific_key),
+                None => format!("`{}`", crate::forwarding_options::FORWARD_OPTION_VIA)
+            };
+            MethodAdaptError::Unforwardable(method_name.span(), format!(
+                "Method `{}` takes `{}`, but there isn't an accessor of the component for it: {}.",
+                method_name, receiver_desc, keys
+            ))
+        })
+    }
+
+    /// Implements a method by calling the trait method on the component reached through the accessor
+    /// of its receiver, as `(&self.parts.wing).method(args)`.
+    /// Associated functions are forwarded to the declared type of the submember, if any.
+    fn create_accessor_call(
+        context: &MethodForwardingContext,
+        method_sig: &Signature,
+        base_hash:&str
+    ) -> Result<Self, MethodAdaptError>
+    {
+        let signature_parts = MethodSignatureParts::extract(method_sig)?;
         let method_name = &method_sig.ident;
-        
+        let target_desc = context.describe_target();
+
+        let forward_call = match signature_parts.receiver {
+            Some((_, receiver_kind @ (ReceiverKind::Ref | ReceiverKind::RefMut | ReceiverKind::Value))) => {
+                let accessor = Self::find_method_accessor(context.options, method_name, receiver_kind)?;
+                let component_access = generate_accessor_access(accessor, receiver_kind);
+                let parameters = context.generate_proxied_parameters(method_sig, &signature_parts, &target_desc)?;
+                context.generate_component_call(None, component_access, method_sig, &parameters)
+            },
+            Some(_) => {
+                return Err(MethodAdaptError::Unforwardable(method_name.span(), format!(
+                    "Method `{}` cannot be forwarded through an accessor, as it requires a `self`, `&self` or `&mut self` receiver.",
+                    method_name
+                )));
+            },
+            None => {
+                let parameters:Vec<TkStream> = signature_parts.parameter_ids.iter().map(|param_id| param_id.to_token_stream()).collect();
+                context.generate_static_call(method_sig, &parameters)?
+            }
+        };
+
+        let accessors = [&context.options.via, &context.options.via_ref, &context.options.via_mut];
+        let accessors_key = accessors.iter().map(|accessor| accessor.to_token_stream()).collect::<Vec<TkStream>>();
+        Ok(Self::assemble(
+            method_sig,
+            &signature_parts,
+            forward_call,
+            &target_desc,
+            context.trait_path,
+            &quote::quote!(via #(#accessors_key);*),
+            base_hash
+        ))
+    }
+
+    /// Implements a method by calling the trait method on the component guarded by the cell of the submember,
+    /// as `{ let __cell_guard = RefCell::borrow(&self.member); <T as Trait>::method(&*__cell_guard, args) }`.
+    fn create_cell_call(
+        context: &MethodForwardingContext,
+        method_sig: &Signature,
+        cell_proxy: &CellProxy,
+        base_hash:&str
+    ) -> Result<Self, MethodAdaptError>
+    {
+        let struct_member = context.submember;
+        let signature_parts = MethodSignatureParts::extract(method_sig)?;
+        let method_name = &method_sig.ident;
+        let target_desc = format!("the component in the cell of the field `{}`", struct_member.member_name());
+        let parameters = context.generate_proxied_parameters(method_sig, &signature_parts, &target_desc)?;
+
+        let component_ty = struct_member.member_ty.as_ref().and_then(peel_cell_type);
+        let forward_call = match signature_parts.receiver {
+            Some((_, receiver_kind)) => {
+                cell_proxy.check_method(method_sig, receiver_kind)?;
+                let member = &struct_member.member;
+                let guard_decl = cell_proxy.generate_guard_decl(struct_member, quote::quote!(&$self_token.#member), receiver_kind, method_name)?;
+                let component_arg = cell_proxy.generate_component_arg(receiver_kind);
+                let component_ty = generate_proxied_component_type(component_ty);
+                let guarded_call = context.generate_component_call(component_ty.as_ref(), component_arg, method_sig, &parameters);
+                quote::quote!({
+                    #guard_decl
+                    #guarded_call
+                })
+            },
+            None => {
+                let component_ty = component_ty.ok_or(MethodAdaptError::NotDispatchable(method_name.span()))?;
+                context.generate_trait_call(&component_ty.to_token_stream(), None, method_sig, &parameters)
+            }
+        };
+
+        Ok(Self::assemble(
+            method_sig,
+            &signature_parts,
+            forward_call,
+            &target_desc,
+            context.trait_path,
+            &quote::quote!(#struct_member cell),
+            base_hash
+        ))
+    }
+
+    /// Implements a method by calling the trait method on every element of the submember collection,
+    /// and combining the returned values with the aggregator of the method:
+    /// `self.member.iter().map(|__element| <T as Trait>::method(__element, args.clone())).sum()`.
+    fn create_broadcast_call(
+        context: &MethodForwardingContext,
+        method_sig: &Signature,
+        broadcast_proxy: &BroadcastProxy,
+        base_hash:&str
+    ) -> Result<Self, MethodAdaptError>
+    {
+        let struct_member = context.submember;
+        let signature_parts = MethodSignatureParts::extract(method_sig)?;
+        let method_name = &method_sig.ident;
+        let target_desc = format!("the elements of the field `{}`", struct_member.member_name());
+        let parameters = context.generate_proxied_parameters(method_sig, &signature_parts, &target_desc)?;
+
+        let element_member = broadcast_proxy.element_member(struct_member);
+        let forward_call = match signature_parts.receiver {
+            Some((_, receiver_kind)) => {
+                let elements = broadcast_proxy.generate_elements(struct_member, receiver_kind, method_name)?;
+                let element_arg = broadcast_proxy.generate_element_arg(struct_member, receiver_kind);
+                let element_ty = receiver_kind.generate_component_type(&element_member, context.options);
+                let element_parameters = broadcast_proxy.generate_parameters(&signature_parts);
+                let element_call = context.generate_component_call(element_ty.as_ref(), element_arg, method_sig, &element_parameters);
+                match broadcast_proxy.generate_aggregation(method_sig)? {
+                    Some(aggregation) => quote::quote!(#elements.map(|__element| #element_call)#aggregation),
+                    None => quote::quote!(
+                        for __element in #elements {
+                            #element_call;
+                        }
+                    )
+                }
+            },
+            None => {
+                // associated functions are called on the type of the elements
+                let element_ty = ReceiverKind::Ref.peeled_member_type(&element_member).or(element_member.member_ty.as_ref()).ok_or(
+                    MethodAdaptError::NotDispatchable(method_name.span())
+                )?;
+                context.generate_trait_call(&element_ty.to_token_stream(), None, method_sig, &parameters)
+            }
+        };
+
+        Ok(Self::assemble(
+            method_sig,
+            &signature_parts,
+            forward_call,
+            &target_desc,
+            context.trait_path,
+            &quote::quote!(#struct_member broadcast),
+            base_hash
+        ))
+    }
+
+    /// Implements a method by calling the trait method on a snapshot of the component in the `ArcSlot` submember:
+    /// `{ let __slot_component = self.member.load(); <T as Trait>::method(&*__slot_component, args) }`.
+    fn create_slot_call(
+        context: &MethodForwardingContext,
+        method_sig: &Signature,
+        component_ty: &syn::Type,
+        base_hash:&str
+    ) -> Result<Self, MethodAdaptError>
+    {
+        let struct_member = context.submember;
+        let signature_parts = MethodSignatureParts::extract(method_sig)?;
+        let target_desc = format!("the component of the `ArcSlot` field `{}`", struct_member.member_name());
+        let parameters = context.generate_proxied_parameters(method_sig, &signature_parts, &target_desc)?;
+
+        let component_ty = component_ty.to_token_stream();
+        let forward_call = match signature_parts.receiver {
+            Some((_, receiver_kind)) => {
+                let slot_load = generate_slot_load(struct_member, receiver_kind, method_sig)?;
+                let component_call = context.generate_component_call(Some(&component_ty), quote::quote!(&*__slot_component), method_sig, &parameters);
+                quote::quote!({
+                    #slot_load
+                    #component_call
+                })
+            },
+            None => context.generate_trait_call(&component_ty, None, method_sig, &parameters)
+        };
+
+        Ok(Self::assemble(
+            method_sig,
+            &signature_parts,
+            forward_call,
+            &target_desc,
+            context.trait_path,
+            &quote::quote!(#struct_member slot),
+            base_hash
+        ))
+    }
+
+    /// Implements a method by trying it on the submember and the links of the chain in order, until one of them answers:
+    /// `'__chain: { if let Some(__answer) = <T as Trait>::method(&self.member, args) { break '__chain Some(__answer); } ... None }`.
+    /// It's `None` for the methods that aren't chained.
+    fn create_chained_call(
+        context: &MethodForwardingContext,
+        method_sig: &Signature,
+        chain_proxy: &ChainProxy,
+        base_hash:&str
+    ) -> Result<Option<Self>, MethodAdaptError>
+    {
+        let struct_member = context.submember;
+        let signature_parts = MethodSignatureParts::extract(method_sig)?;
+        let Some((_, receiver_kind)) = signature_parts.receiver else {return Ok(None)};
+
+        // the links receive copies of the arguments, made by the chain
+        let chained_call = chain_proxy.generate_chain(struct_member, context.trait_path, method_sig, &signature_parts, receiver_kind)?;
+        let Some(chained_call) = chained_call else {return Ok(None)};
+        let target_desc = format!("the chain from the field `{}`", struct_member.member_name());
+        context.generate_proxied_parameters(method_sig, &signature_parts, &target_desc)?;
+
+        Ok(Some(Self::assemble(
+            method_sig,
+            &signature_parts,
+            chained_call,
+            &target_desc,
+            context.trait_path,
+            &quote::quote!(#struct_member chain),
+            base_hash
+        )))
+    }
+
+    /// Implements a method by initializing the lazy submember, and calling the trait method on its component:
+    /// `<T as Trait>::method(self.member.get_or_init(init_fn), args)`.
+    fn create_lazy_call(
+        context: &MethodForwardingContext,
+        method_sig: &Signature,
+        lazy_proxy: &LazyProxy,
+        base_hash:&str
+    ) -> Result<Self, MethodAdaptError>
+    {
+        let struct_member = context.submember;
+        let signature_parts = MethodSignatureParts::extract(method_sig)?;
+        let method_name = &method_sig.ident;
+        let target_desc = format!("the component of the lazy field `{}`", struct_member.member_name());
+        let parameters = context.generate_proxied_parameters(method_sig, &signature_parts, &target_desc)?;
+
+        let component_ty = struct_member.member_ty.as_ref().and_then(peel_lazy_type);
+        let forward_call = match signature_parts.receiver {
+            Some((_, receiver_kind)) => {
+                let component_arg = lazy_proxy.generate_component_arg(struct_member, receiver_kind, method_name)?;
+                let component_ty = generate_proxied_component_type(component_ty);
+                context.generate_component_call(component_ty.as_ref(), component_arg, method_sig, &parameters)
+            },
+            None => {
+                let component_ty = component_ty.ok_or(MethodAdaptError::NotDispatchable(method_name.span()))?;
+                context.generate_trait_call(&component_ty.to_token_stream(), None, method_sig, &parameters)
+            }
+        };
+
+        Ok(Self::assemble(
+            method_sig,
+            &signature_parts,
+            forward_call,
+            &target_desc,
+            context.trait_path,
+            &quote::quote!(#struct_member lazy),
+            base_hash
+        ))
+    }
+
+    /// Implements a method by unwrapping or upgrading the optional submember, and calling the trait method on its component.
+    /// When the component is missing, the method follows its fallback:
+    /// `match &self.member { Some(__component) => <T as Trait>::method(__component, args), None => fallback }`.
+    fn create_optional_call(
+        context: &MethodForwardingContext,
+        method_sig: &Signature,
+        optional_proxy: &OptionalProxy,
+        base_hash:&str
+    ) -> Result<Self, MethodAdaptError>
+    {
+        let struct_member = context.submember;
+        let signature_parts = MethodSignatureParts::extract(method_sig)?;
+        let method_name = &method_sig.ident;
+        let target_desc = format!("the component of the optional field `{}`", struct_member.member_name());
+        let parameters = context.generate_proxied_parameters(method_sig, &signature_parts, &target_desc)?;
+
+        let component_ty = struct_member.member_ty.as_ref().and_then(|member_ty| context.options.proxied_component_type(member_ty));
+        let forward_call = match signature_parts.receiver {
+            Some((_, receiver_kind @ (ReceiverKind::Ref | ReceiverKind::RefMut | ReceiverKind::Value))) => {
+                optional_proxy.check_output(method_sig)?;
+                let cell_proxy = context.options.cell_proxy();
+                if let Some(cell_proxy) = &cell_proxy {
+                    cell_proxy.check_method(method_sig, receiver_kind)?;
+                }
+
+                let scrutinee = optional_proxy.generate_scrutinee(struct_member, receiver_kind);
+                let (guard_decl, component_arg) = optional_proxy.generate_component_arg(
+                    struct_member, receiver_kind, cell_proxy.as_ref(), method_name
+                )?;
+                let component_ty = generate_proxied_component_type(component_ty);
+                let component_call = context.generate_component_call(component_ty.as_ref(), component_arg, method_sig, &parameters);
+                let fallback = optional_proxy.generate_fallback(struct_member, context.trait_path, method_name, &parameters);
+                quote::quote!(
+                    match #scrutinee {
+                        ::core::option::Option::Some(__component) => {
+                            #guard_decl
+                            #component_call
+                        },
+                        ::core::option::Option::None => { #fallback }
+                    }
+                )
+            },
+            Some(_) => {
+                return Err(MethodAdaptError::Unforwardable(method_name.span(), format!(
+                    "Method `{}` cannot be forwarded to the optional field `{}`, as it requires a `self`, `&self` or `&mut self` receiver.",
+                    method_name, struct_member.member_name()
+                )));
+            },
+            None => {
+                let component_ty = component_ty.ok_or(MethodAdaptError::NotDispatchable(method_name.span()))?;
+                context.generate_trait_call(&component_ty.to_token_stream(), None, method_sig, &parameters)
+            }
+        };
+
+        Ok(Self::assemble(
+            method_sig,
+            &signature_parts,
+            forward_call,
+            &target_desc,
+            context.trait_path,
+            &quote::quote!(#struct_member optional),
+            base_hash
+        ))
+    }
+
+    /// Implements a method excluded from forwarding by calling the inherent method `inherent` of the composite,
+    /// as `Self::inherent::<G...>(self, args)`. The inherent method is named differently from the methods of the trait,
+    /// so a missing inherent method is reported as such, instead of resolving to the trait method itself.
+    pub(crate) fn create_inherent_call(
+        method_sig: &Signature,
+        inherent: &Ident,
+        trait_path: &syn::Path,
+        base_hash:&str
+    ) -> Result<Self, MethodAdaptError>
+    {
+        let signature_parts = MethodSignatureParts::extract(method_sig)?;
+        let await_call = generate_await(method_sig);
+        let turbofish = generate_turbofish(method_sig);
+        let receiver_arg = signature_parts.receiver.map(|_| quote::quote!($self_token));
+        let parameters:Vec<TkStream> = signature_parts.parameter_ids.iter().map(|param_id| param_id.to_token_stream()).collect();
+
+        let inherent_call = generate_guarded_call(method_sig, receiver_arg, &parameters, |receiver_arg, parameters| {
+            let arguments = receiver_arg.iter().chain(parameters.iter());
+            quote::quote!(Self::#inherent #turbofish ( #(#arguments),* ) #await_call)
+        });
+
+        Ok(Self::assemble(
+            method_sig,
+            &signature_parts,
+            inherent_call,
+            &format!("the inherent method `{}`", inherent),
+            trait_path,
+            &quote::quote!(inherent #inherent),
+            base_hash
+        ))
+    }
+
+    /// Emits the declarative macro that declares the forwarded method with the body `forward_call`,
+    /// and its invocation inside the trait implementation.
+    /// `target_desc` names the component in the generated documentation, and `target_key` distinguishes
+    /// the macro names of the same method between components.
+    pub(crate) fn assemble(
+        method_sig: &Signature,
+        signature_parts: &MethodSignatureParts,
+        forward_call: TkStream,
+        target_desc: &str,
+        trait_path: &syn::Path,
+        target_key: &TkStream,
+        base_hash:&str
+    ) -> Self
+    {
+        let method_name = &method_sig.ident;
+        let receiver_decl = signature_parts.generate_receiver_decl();
+        let signature_params = &signature_parts.signature_params;
+
         // If it doesn't have a return type, put a semi colon
         let method_output = method_sig.output.clone();
         let semi_end:Option<syn::token::Semi>  = match &method_output {
@@ -136,14 +1373,29 @@ impl TraitMethodImplMacros
             _ => { None }
         };
 
+        let method_constness = &method_sig.constness;
+        let method_asyncness = &method_sig.asyncness;
+        let method_unsafety = &method_sig.unsafety;
+        let method_abi = &method_sig.abi;
         let method_generics = &method_sig.generics;
         let method_where = &method_sig.generics.where_clause;
 
+        // Unsafe methods delegate their safety contract to the same method of the submember.
+        // Their body isn't an unsafe context, so only the forwarded call is placed in an `unsafe` block,
+        // which is unused when a designated inherent method is safe.
+        let safety_doc = method_unsafety.map(|_| {
+            let safety_note = format!(
+                "# Safety\n\nForwarded to {}, so the caller must uphold the safety contract of `{}::{}` for it.",
+                target_desc, trait_path.to_token_stream().to_string().replace(' ', ""), method_name
+            );
+            quote::quote!(#[doc = #safety_note] #[deny(unsafe_op_in_unsafe_fn)] #[allow(unused_unsafe)])
+        });
+        let forward_body = quote::quote!(#forward_call #semi_end);
 
         // calculate macro name with signature hash
         let inner_macro_method_name_str = format!("macromethod_{}_{}",
-            method_name.to_string(),
-            generate_macro_signature_hash(struct_member, method_sig, base_hash)
+            method_name,
+            generate_macro_signature_hash(target_key, method_sig, base_hash)
         );
 
         let inner_macro_method_name = Ident::new(&inner_macro_method_name_str, method_name.span());
@@ -152,11 +1404,13 @@ impl TraitMethodImplMacros
         let mdecl = quote::quote!(
             macro_rules! #inner_macro_method_name {
                 ($self_token:ident) => {
-                    
-                    fn #method_name #method_generics (#receiver_pat $self_token #(,#signature_params)* ) #method_output
+
+                    #safety_doc
+                    #method_constness #method_asyncness #method_unsafety #method_abi
+                    fn #method_name #method_generics (#receiver_decl #(#signature_params),* ) #method_output
                     #method_where
                     {
-                        $self_token.#struct_member.#method_name( #(#parameters),* ) #semi_end
+                        #forward_body
                     }
                 };
             }
@@ -165,8 +1419,7 @@ impl TraitMethodImplMacros
         // macro invokation should be inserted inside trait implementation
         let minvoke = quote::quote!(#inner_macro_method_name!(self););
 
-        Ok(Self{macro_decl:mdecl, macro_invoke:minvoke})
-
+        Self{macro_decl:mdecl, macro_invoke:minvoke}
     }
 
 }
@@ -0,0 +1,157 @@
+/*
+Hereditary
+Autors: Francisco Leon <https://github.com/superoptimo>
+License Apache-2.0
+*/
+
+use proc_macro2::token_stream::TokenStream as TkStream;
+use quote::ToTokens;
+use syn::{Ident, Type};
+
+use crate::cell_proxy::{CellProxy, type_borrows};
+use crate::forwarding_options::{ForwardingOptions, FORWARD_FALLBACK_DEFAULT, FORWARD_FALLBACK_PANIC};
+use crate::method_member_adapter::{ForwardingMember, MethodAdaptError, ReceiverKind, smart_pointer_parts};
+
+/// `optional = option` forwards to the component of an `Option` field when it's `Some`
+pub const FORWARD_OPTIONAL_OPTION: &str = "option";
+/// `optional = weak` forwards to the component of a `Weak` field when it can be upgraded
+pub const FORWARD_OPTIONAL_WEAK: &str = "weak";
+
+/// Fields whose component can be missing when a method is called
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum OptionalKind
+{
+    Option,
+    Weak
+}
+
+impl OptionalKind
+{
+    /// Optional kind named by the `optional` option
+    pub(crate) fn from_option(optional:&syn::Path) -> Option<Self>
+    {
+        if optional.is_ident(FORWARD_OPTIONAL_OPTION) {Some(Self::Option)}
+        else if optional.is_ident(FORWARD_OPTIONAL_WEAK) {Some(Self::Weak)}
+        else {None}
+    }
+}
+
+/// Type held by an optional field, like `X` in `Option<X>` or `Weak<X>`
+pub(crate) fn peel_optional_type(member_ty:&Type) -> Option<&Type>
+{
+    let (wrapper_name, inner) = smart_pointer_parts(member_ty)?;
+    if wrapper_name == "Option" || wrapper_name == "Weak" {Some(inner)} else {None}
+}
+
+/// Access to a component that can be missing, which is unwrapped or upgraded on every call
+pub(crate) struct OptionalProxy<'a>
+{
+    pub(crate) kind:OptionalKind,
+    pub(crate) options:&'a ForwardingOptions
+}
+
+impl OptionalProxy<'_>
+{
+    /// Expression matched against `Some(__component)`, which obtains the component for the receiver.
+    pub(crate) fn generate_scrutinee(&self, submember:&ForwardingMember, receiver_kind:ReceiverKind) -> TkStream
+    {
+        let member = &submember.member;
+        match (self.kind, receiver_kind) {
+            (OptionalKind::Option, ReceiverKind::Ref) => quote::quote!(&$self_token.#member),
+            (OptionalKind::Option, ReceiverKind::RefMut) => quote::quote!(&mut $self_token.#member),
+            (OptionalKind::Option, _) => quote::quote!($self_token.#member),
+            (OptionalKind::Weak, _) => quote::quote!($self_token.#member.upgrade())
+        }
+    }
+
+    /// Member that holds the component in the `Some` arm, with the type held by the optional field
+    pub(crate) fn inner_member(&self, submember:&ForwardingMember) -> ForwardingMember
+    {
+        ForwardingMember{
+            member:submember.member.clone(),
+            member_ty:submember.member_ty.as_ref().and_then(peel_optional_type).cloned()
+        }
+    }
+
+    /// Argument that passes the component of the `Some` arm to the trait method.
+    /// Components of `Option` fields are bound by reference for borrowing receivers, and dereferenced
+    /// when they are stored behind a pointer. Upgraded `Weak` fields only give shared access to the component,
+    /// unless it's guarded by a cell.
+    pub(crate) fn generate_component_arg(
+        &self,
+        submember:&ForwardingMember,
+        receiver_kind:ReceiverKind,
+        cell_proxy:Option<&CellProxy>,
+        method_name:&Ident
+    ) -> Result<(Option<TkStream>, TkStream), MethodAdaptError>
+    {
+        let inner_member = self.inner_member(submember);
+        match (self.kind, cell_proxy) {
+            (OptionalKind::Option, _) => {
+                let peeled = receiver_kind.peeled_member_type(&inner_member).is_some();
+                let component_arg = match (receiver_kind, peeled) {
+                    (ReceiverKind::Ref, true) => quote::quote!(&**__component),
+                    (ReceiverKind::RefMut, true) => quote::quote!(&mut **__component),
+                    (_, true) => quote::quote!(*__component),
+                    (_, false) => quote::quote!(__component)
+                };
+                Ok((None, component_arg))
+            },
+            (OptionalKind::Weak, Some(cell_proxy)) if receiver_kind != ReceiverKind::Value => {
+                let guard_decl = cell_proxy.generate_guard_decl(&inner_member, quote::quote!(&*__component), receiver_kind, method_name)?;
+                Ok((Some(guard_decl), cell_proxy.generate_component_arg(receiver_kind)))
+            },
+            (OptionalKind::Weak, None) if receiver_kind == ReceiverKind::Ref => Ok((None, quote::quote!(&*__component))),
+            (OptionalKind::Weak, _) => Err(MethodAdaptError::Unforwardable(method_name.span(), format!(
+                "Method `{}` requires exclusive access to the component of the `Weak` field `{}`, which is shared. It's supported for `&mut self` methods with the `cell` option.",
+                method_name, submember.member_name()
+            )))
+        }
+    }
+
+    /// Checks that the output of the method can outlive the upgraded `Rc` or `Arc` of a `Weak` field
+    pub(crate) fn check_output(&self, method_sig:&syn::Signature) -> Result<(), MethodAdaptError>
+    {
+        match &method_sig.output {
+            syn::ReturnType::Type(_, output_ty) if self.kind == OptionalKind::Weak && type_borrows(output_ty) => {
+                Err(MethodAdaptError::Unforwardable(method_sig.ident.span(), format!(
+                    "Method `{}` returns a borrowed value, which would outlive the component upgraded from the `Weak` field.",
+                    method_sig.ident
+                )))
+            },
+            _ => Ok(())
+        }
+    }
+
+    /// Body of the method when the component is missing, following the fallback of the method or the one of the trait:
+    /// `default` returns `Default::default()`, `panic` panics with a descriptive message (also when there isn't a fallback),
+    /// and a path calls that function with the receiver and the arguments of the method.
+    pub(crate) fn generate_fallback(
+        &self,
+        submember:&ForwardingMember,
+        trait_path:&syn::Path,
+        method_name:&Ident,
+        parameters:&[TkStream]
+    ) -> TkStream
+    {
+        let fallback = self.options.method_fallback.iter().find(
+            |method_fallback| method_fallback.method == *method_name
+        ).map(|method_fallback| &method_fallback.fallback).or(self.options.fallback.as_ref());
+
+        match fallback {
+            Some(fallback) if fallback.is_ident(FORWARD_FALLBACK_DEFAULT) => quote::quote!(::core::default::Default::default()),
+            Some(fallback) if !fallback.is_ident(FORWARD_FALLBACK_PANIC) => quote::quote!(#fallback($self_token #(, #parameters)*)),
+            _ => {
+                let missing = match self.kind {
+                    OptionalKind::Option => format!("the field `{}` is `None`", submember.member_name()),
+                    OptionalKind::Weak => format!("the component of the field `{}` was dropped", submember.member_name())
+                };
+                let panic_message = format!(
+                    "{}, so it cannot forward `{}::{}`",
+                    missing, trait_path.to_token_stream().to_string().replace(' ', ""), method_name
+                );
+                quote::quote!(::core::panic!("{}", #panic_message))
+            }
+        }
+    }
+}
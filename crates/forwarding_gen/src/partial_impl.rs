@@ -0,0 +1,62 @@
+/*
+Hereditary
+Autors: Francisco Leon <https://github.com/superoptimo>
+License Apache-2.0
+*/
+
+use proc_macro2::token_stream::TokenStream as TkStream;
+use quote::ToTokens;
+use syn::{ItemImpl, Ident};
+
+/// Name of the macro that expands to the forwarded items of a trait implementation, which the derive declares when the
+/// filter of the trait excludes methods without designating their inherent methods.
+/// The partial implementation of the excluded methods invokes it, so both are named after the type and the trait.
+pub(crate) fn partial_impl_macro_name(type_name:&Ident, trait_name:&Ident) -> Ident
+{
+    Ident::new(&format!("forwardpartial_{}_{}", type_name, trait_name), type_name.span())
+}
+
+/// Last identifier of a path, which names the type or the trait regardless of the module and the generic arguments
+fn last_path_ident(path:&syn::Path) -> Option<&Ident>
+{
+    path.segments.last().map(|segment| &segment.ident)
+}
+
+/// Partial trait implementation with the methods excluded by `#[forward_derive(Trait(except(..)))]`,
+/// which receives the forwarded methods of the derive.
+pub struct ForwardingPartialImplInput
+{
+    pub trait_impl:ItemImpl,
+    pub macro_name:Ident
+}
+
+impl syn::parse::Parse for ForwardingPartialImplInput
+{
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let trait_impl:ItemImpl = input.parse()?;
+
+        let trait_name = trait_impl.trait_.as_ref().and_then(|(_, trait_path, _)| last_path_ident(trait_path)).ok_or_else(
+            || syn::Error::new(trait_impl.impl_token.span, "Required a Trait name.")
+        )?;
+
+        let type_name = match trait_impl.self_ty.as_ref() {
+            syn::Type::Path(type_path) if type_path.qself.is_none() => last_path_ident(&type_path.path),
+            _ => None
+        }.ok_or_else(|| syn::Error::new_spanned(&trait_impl.self_ty, "The partial implementation requires the name of the derived type."))?;
+
+        let macro_name = partial_impl_macro_name(type_name, trait_name);
+        Ok(Self{trait_impl, macro_name})
+    }
+}
+
+impl ForwardingPartialImplInput
+{
+    /// Appends the invocation of the forwarded items to the implementation
+    pub fn generate_macro_code(&self) -> TkStream
+    {
+        let mut trait_impl = self.trait_impl.clone();
+        let macro_name = &self.macro_name;
+        trait_impl.items.push(syn::ImplItem::Verbatim(quote::quote!(#macro_name!{})));
+        trait_impl.to_token_stream()
+    }
+}
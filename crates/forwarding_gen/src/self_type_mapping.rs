@@ -0,0 +1,209 @@
+/*
+Hereditary
+Autors: Francisco Leon <https://github.com/superoptimo>
+License Apache-2.0
+*/
+
+use proc_macro2::token_stream::TokenStream as TkStream;
+use syn::fold::Fold;
+use syn::Type;
+
+/// Detects the bare `Self` type inside another type.
+/// Projections like `Self::Item` or `<Self as Trait>::Item` don't count, because
+/// they name associated types instead of the composite.
+struct SelfTypeFinder
+{
+    found:bool
+}
+
+impl Fold for SelfTypeFinder
+{
+    fn fold_type_path(&mut self, tpath: syn::TypePath) -> syn::TypePath {
+        if tpath.qself.is_none() && tpath.path.is_ident("Self") {
+            self.found = true;
+            return tpath;
+        }
+
+        // skip qualified self, but check the generic arguments of the path
+        syn::TypePath{
+            qself:tpath.qself,
+            path:self.fold_path(tpath.path)
+        }
+    }
+}
+
+/// Checks if the bare `Self` type appears in `ty`
+pub(crate) fn type_mentions_self(ty:&Type) -> bool
+{
+    let mut finder = SelfTypeFinder{found:false};
+    finder.fold_type(ty.clone());
+    finder.found
+}
+
+pub(crate) fn is_self_type(ty:&Type) -> bool
+{
+    match ty {
+        Type::Path(tpath) => tpath.qself.is_none() && tpath.path.is_ident("Self"),
+        Type::Paren(tparen) => is_self_type(&tparen.elem),
+        Type::Group(tgroup) => is_self_type(&tgroup.elem),
+        _ => false
+    }
+}
+
+/// Extracts the generic type arguments of `Wrapper<T, ...>`, if the last path segment is named `wrapper_name`.
+fn wrapper_type_arguments<'a>(ty:&'a Type, wrapper_name:&str) -> Option<Vec<&'a Type>>
+{
+    if let Type::Path(tpath) = ty {
+        let last = tpath.path.segments.last()?;
+        if last.ident != wrapper_name {
+            return None;
+        }
+
+        match &last.arguments {
+            syn::PathArguments::AngleBracketed(args) => {
+                args.args.iter().map(|arg| match arg {
+                    syn::GenericArgument::Type(argty) => Some(argty),
+                    _ => None
+                }).collect()
+            },
+            _ => None
+        }
+    }
+    else {None}
+}
+
+/// Generates the expression that converts the value returned by the component (`value_expr`),
+/// into the output type `output_ty` of the composite, by rebuilding every `Self` instance with `rebuild`.
+///
+/// Supported forms are `Self`, `Option<Self>`, `Result<Self, E>`, `Vec<Self>`, tuples and the nesting of them.
+/// Returns `None` when the output mentions `Self` in a form that cannot be rebuilt.
+/// Outputs without `Self` are returned unchanged.
+pub(crate) fn generate_output_rebuild(
+    output_ty:&Type,
+    value_expr:TkStream,
+    rebuild:&dyn Fn(TkStream) -> TkStream
+) -> Option<TkStream>
+{
+    if !type_mentions_self(output_ty) {
+        return Some(value_expr);
+    }
+
+    if is_self_type(output_ty) {
+        return Some(rebuild(value_expr));
+    }
+
+    if let Some([inner_ty]) = wrapper_type_arguments(output_ty, "Option").as_deref() {
+        let inner_rebuild = generate_output_rebuild(inner_ty, quote::quote!(__component), rebuild)?;
+        return Some(quote::quote!(#value_expr.map(|__component| #inner_rebuild)));
+    }
+
+    if let Some([inner_ty, error_ty]) = wrapper_type_arguments(output_ty, "Result").as_deref() {
+        if type_mentions_self(error_ty) {
+            return None;
+        }
+        let inner_rebuild = generate_output_rebuild(inner_ty, quote::quote!(__component), rebuild)?;
+        return Some(quote::quote!(#value_expr.map(|__component| #inner_rebuild)));
+    }
+
+    if let Some([inner_ty]) = wrapper_type_arguments(output_ty, "Vec").as_deref() {
+        let inner_rebuild = generate_output_rebuild(inner_ty, quote::quote!(__component), rebuild)?;
+        return Some(quote::quote!(
+            ::core::iter::Iterator::collect::<::std::vec::Vec<_>>(
+                ::core::iter::Iterator::map(::core::iter::IntoIterator::into_iter(#value_expr), |__component| #inner_rebuild)
+            )
+        ));
+    }
+
+    if let Type::Tuple(ttuple) = output_ty {
+        let element_ids:Vec<syn::Ident> = (0..ttuple.elems.len()).map(
+            |i| syn::Ident::new(&format!("__component{}", i), proc_macro2::Span::call_site())
+        ).collect();
+
+        let element_rebuilds = ttuple.elems.iter().zip(element_ids.iter()).map(
+            |(elem_ty, elem_id)| generate_output_rebuild(elem_ty, quote::quote!(#elem_id), rebuild)
+        ).collect::<Option<Vec<TkStream>>>()?;
+
+        return Some(quote::quote!({
+            let (#(#element_ids,)*) = #value_expr;
+            (#(#element_rebuilds,)*)
+        }));
+    }
+
+    None
+}
+
+/// How the component of the trait is stored in the submember, which decides how the members of other instances are projected
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum MemberIndirection
+{
+    /// the member is the component
+    Direct,
+    /// the component is owned by a `Box`, so it can be moved out of it
+    Owned,
+    /// the component is borrowed mutably by a `&mut` member
+    Unique,
+    /// the component is shared by a reference, `Rc`, `Arc` or pinned, so it can only be borrowed
+    Shared
+}
+
+/// Generates the expression that projects an argument of the composite type (`arg_expr`) into the submember,
+/// so it can be passed to the method of the component.
+///
+/// Supported forms are `Self`, `&Self`, `&mut Self`, and `Option<..>` or `Vec<..>` of them. Arguments taken by value
+/// move the submember out of the other instance. Members that store the component behind a pointer are dereferenced,
+/// which only moves the component out of a `Box`, and only borrows it mutably from a `Box` or a `&mut` reference.
+/// Slices like `&[Self]` aren't supported, as their projection would clone the submember of every element.
+/// Returns the reason when the argument mentions `Self` in a form that cannot be projected.
+/// Arguments without `Self` are returned unchanged.
+pub(crate) fn generate_input_projection(
+    arg_ty:&Type,
+    arg_expr:TkStream,
+    member:&dyn quote::ToTokens,
+    indirection:MemberIndirection
+) -> Result<TkStream, &'static str>
+{
+    if !type_mentions_self(arg_ty) {
+        return Ok(arg_expr);
+    }
+
+    if is_self_type(arg_ty) {
+        return match indirection {
+            MemberIndirection::Direct => Ok(quote::quote!(#arg_expr.#member)),
+            MemberIndirection::Owned => Ok(quote::quote!(*#arg_expr.#member)),
+            _ => Err("the component cannot be moved out of the member")
+        };
+    }
+
+    match arg_ty {
+        Type::Reference(tref) if is_self_type(&tref.elem) => {
+            let mutability = &tref.mutability;
+            match (indirection, mutability) {
+                (MemberIndirection::Direct, _) => Ok(quote::quote!(&#mutability #arg_expr.#member)),
+                (MemberIndirection::Shared, Some(_)) => Err("the component is shared by the member, so it cannot be borrowed mutably"),
+                _ => Ok(quote::quote!(&#mutability *#arg_expr.#member))
+            }
+        },
+        Type::Reference(tref) if matches!(tref.elem.as_ref(), Type::Slice(tslice) if type_mentions_self(&tslice.elem)) => {
+            Err("slices of `Self` cannot be projected without cloning the member of every element, so they can be taken as `Vec<&Self>` instead")
+        },
+        Type::Paren(tparen) => generate_input_projection(&tparen.elem, arg_expr, member, indirection),
+        Type::Group(tgroup) => generate_input_projection(&tgroup.elem, arg_expr, member, indirection),
+        _ => {
+            if let Some([inner_ty]) = wrapper_type_arguments(arg_ty, "Option").as_deref() {
+                let inner_projection = generate_input_projection(inner_ty, quote::quote!(__other), member, indirection)?;
+                return Ok(quote::quote!(#arg_expr.map(|__other| #inner_projection)));
+            }
+
+            if let Some([inner_ty]) = wrapper_type_arguments(arg_ty, "Vec").as_deref() {
+                let inner_projection = generate_input_projection(inner_ty, quote::quote!(__other), member, indirection)?;
+                return Ok(quote::quote!(
+                    ::core::iter::Iterator::collect::<::std::vec::Vec<_>>(
+                        ::core::iter::Iterator::map(::core::iter::IntoIterator::into_iter(#arg_expr), |__other| #inner_projection)
+                    )
+                ));
+            }
+
+            Err("it takes `Self` in a form that isn't supported")
+        }
+    }
+}
@@ -0,0 +1,112 @@
+/*
+Hereditary
+Autors: Francisco Leon <https://github.com/superoptimo>
+License Apache-2.0
+*/
+
+use proc_macro2::token_stream::TokenStream as TkStream;
+use syn::{Ident, Signature, Type};
+
+use crate::cell_proxy::type_borrows;
+use crate::method_member_adapter::{ForwardingMember, MethodAdaptError, ReceiverKind, smart_pointer_parts};
+
+/// Type of the component in an `ArcSlot` field, like `T` in `ArcSlot<T>`
+pub(crate) fn peel_slot_type(member_ty:&Type) -> Option<&Type>
+{
+    smart_pointer_parts(member_ty).and_then(|(slot_name, component_ty)| if slot_name == "ArcSlot" {Some(component_ty)} else {None})
+}
+
+/// Checks that a swappable field holds its component behind a `Box`, `Rc`, `Arc` or `ArcSlot`
+pub(crate) fn check_swappable_type(swappable:&Ident, member_ty:&Type) -> syn::Result<()>
+{
+    match smart_pointer_parts(member_ty) {
+        Some((pointer_name, _)) if ["Box", "Rc", "Arc", "ArcSlot"].iter().any(|slot_name| pointer_name == slot_name) => Ok(()),
+        _ => Err(syn::Error::new(swappable.span(), "Swappable fields are required to hold their component in a `Box`, `Rc`, `Arc` or `ArcSlot`."))
+    }
+}
+
+/// Inherent methods that swap the component of the field: `set_<field>`, `replace_<field>` and `with_<field>`.
+///
+/// `ArcSlot` fields are swapped through `&self`, so any thread sharing the composite can replace the component.
+/// The rest of the fields are swapped through `&mut self`.
+pub(crate) fn generate_slot_methods(slot:&ForwardingMember) -> TkStream
+{
+    let member = &slot.member;
+    let field_name = slot.member_name();
+    let set_id = quote::format_ident!("set_{}", field_name);
+    let replace_id = quote::format_ident!("replace_{}", field_name);
+    let with_id = quote::format_ident!("with_{}", field_name);
+
+    let set_doc = format!("Replaces the component of the field `{}`, which receives the forwarded methods.", field_name);
+    let replace_doc = format!("Replaces the component of the field `{}`, and returns the previous one.", field_name);
+    let with_doc = format!("Returns the composite with another component in the field `{}`.", field_name);
+
+    match slot.member_ty.as_ref().and_then(peel_slot_type) {
+        Some(component_ty) => quote::quote!(
+            #[doc = #set_doc]
+            pub fn #set_id(&self, component: ::std::sync::Arc<#component_ty>) {
+                self.#member.store(component);
+            }
+
+            #[doc = #replace_doc]
+            pub fn #replace_id(&self, component: ::std::sync::Arc<#component_ty>) -> ::std::sync::Arc<#component_ty> {
+                self.#member.swap(component)
+            }
+
+            #[doc = #with_doc]
+            pub fn #with_id(self, component: ::std::sync::Arc<#component_ty>) -> Self {
+                self.#member.store(component);
+                self
+            }
+        ),
+        None => {
+            let member_ty = &slot.member_ty;
+            quote::quote!(
+                #[doc = #set_doc]
+                pub fn #set_id(&mut self, component: #member_ty) {
+                    self.#member = component;
+                }
+
+                #[doc = #replace_doc]
+                pub fn #replace_id(&mut self, component: #member_ty) -> #member_ty {
+                    ::core::mem::replace(&mut self.#member, component)
+                }
+
+                #[doc = #with_doc]
+                pub fn #with_id(mut self, component: #member_ty) -> Self {
+                    self.#member = component;
+                    self
+                }
+            )
+        }
+    }
+}
+
+/// Declares `__slot_component`, the snapshot of the component of an `ArcSlot` field that receives a `&self` method.
+/// The component is shared, so other receivers are unforwardable, as well as outputs that would borrow from the snapshot.
+pub(crate) fn generate_slot_load(
+    submember:&ForwardingMember,
+    receiver_kind:ReceiverKind,
+    method_sig:&Signature
+) -> Result<TkStream, MethodAdaptError>
+{
+    let method_name = &method_sig.ident;
+    if receiver_kind != ReceiverKind::Ref {
+        return Err(MethodAdaptError::Unforwardable(method_name.span(), format!(
+            "Method `{}` requires a `&self` receiver to be forwarded to the shared component of the `ArcSlot` field `{}`.",
+            method_name, submember.member_name()
+        )));
+    }
+
+    if let syn::ReturnType::Type(_, output_ty) = &method_sig.output {
+        if type_borrows(output_ty) {
+            return Err(MethodAdaptError::Unforwardable(method_name.span(), format!(
+                "Method `{}` returns a borrowed value, which would outlive the snapshot of the `ArcSlot` field `{}`.",
+                method_name, submember.member_name()
+            )));
+        }
+    }
+
+    let member = &submember.member;
+    Ok(quote::quote!(let __slot_component = $self_token.#member.load();))
+}
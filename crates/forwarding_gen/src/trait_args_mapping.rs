@@ -0,0 +1,267 @@
+/*
+Hereditary
+Autors: Francisco Leon <https://github.com/superoptimo>
+License Apache-2.0
+*/
+
+use syn::fold::Fold;
+use syn::{Expr, GenericArgument, GenericParam, Generics, Ident, Lifetime, PathArguments, Type};
+
+/// Substitution of the generic parameters of a trait by the arguments given in its path,
+/// so the signatures recorded by `trait_info` (`fn parse(&mut self, input:&'a [T])`) are
+/// declared with the arguments of the implementation (`fn parse(&mut self, input:&'src [Token])`).
+pub(crate) struct TraitArgsMapping
+{
+    lifetimes:Vec<(Lifetime, Lifetime)>,
+    types:Vec<(Ident, Type)>,
+    constants:Vec<(Ident, Expr)>,
+    /// trait bounds of the type parameters, for qualifying their associated types (`I::Item`)
+    type_bounds:Vec<(Ident, Vec<syn::Path>)>
+}
+
+impl TraitArgsMapping
+{
+    /// Pairs the parameters of the trait declaration with the arguments of the trait path.
+    /// Missing type and const arguments take the defaults of the trait declaration.
+    pub(crate) fn create(
+        trait_name:&Ident,
+        trait_generics:&Generics,
+        trait_args:&PathArguments,
+        error_span:proc_macro2::Span
+    ) -> syn::Result<Self>
+    {
+        let args:Vec<&GenericArgument> = match trait_args {
+            PathArguments::None => Vec::new(),
+            PathArguments::AngleBracketed(angle_args) => angle_args.args.iter().collect(),
+            PathArguments::Parenthesized(paren_args) => {
+                return Err(syn::Error::new_spanned(paren_args, "Parenthesized trait arguments are not supported."));
+            }
+        };
+
+        let mut lifetime_args = args.iter().filter_map(|arg| match arg {
+            GenericArgument::Lifetime(lifetime) => Some(lifetime),
+            _ => None
+        });
+
+        let mut value_args = args.iter().filter(|arg| !matches!(arg, GenericArgument::Lifetime(_)));
+
+        let arguments_error = || syn::Error::new(error_span, format!(
+            "Trait `{}` expects the generic arguments `{}`.", trait_name, quote::ToTokens::to_token_stream(trait_generics)
+        ));
+
+        let mut mapping = Self{lifetimes:Vec::new(), types:Vec::new(), constants:Vec::new(), type_bounds:Vec::new()};
+        for param in &trait_generics.params {
+            match param {
+                GenericParam::Lifetime(lifetime_param) => {
+                    let lifetime_arg = lifetime_args.next().ok_or_else(arguments_error)?;
+                    mapping.lifetimes.push((lifetime_param.lifetime.clone(), lifetime_arg.clone()));
+                },
+                GenericParam::Type(type_param) => {
+                    let type_arg = match (value_args.next(), &type_param.default) {
+                        (Some(GenericArgument::Type(type_arg)), _) => type_arg.clone(),
+                        // defaults can refer to the previous parameters
+                        (None, Some(default_ty)) => mapping.fold_type(default_ty.clone()),
+                        _ => { return Err(arguments_error()); }
+                    };
+                    mapping.types.push((type_param.ident.clone(), type_arg));
+                    mapping.type_bounds.push((type_param.ident.clone(), Self::collect_trait_bounds(trait_generics, &type_param.ident)));
+                },
+                GenericParam::Const(const_param) => {
+                    let const_arg = match (value_args.next(), &const_param.default) {
+                        (Some(GenericArgument::Const(const_expr)), _) => const_expr.clone(),
+                        // constant names are parsed as types in generic arguments
+                        (Some(GenericArgument::Type(Type::Path(const_path))), _) if const_path.qself.is_none() => {
+                            Expr::Path(syn::ExprPath{attrs:Vec::new(), qself:None, path:const_path.path.clone()})
+                        },
+                        (None, Some(default_expr)) => mapping.fold_expr(default_expr.clone()),
+                        _ => { return Err(arguments_error()); }
+                    };
+                    mapping.constants.push((const_param.ident.clone(), const_arg));
+                }
+            }
+        }
+
+        if lifetime_args.next().is_some() || value_args.next().is_some() {
+            return Err(arguments_error());
+        }
+
+        Ok(mapping)
+    }
+
+    /// Trait bounds of a type parameter, declared inline or in the where clause
+    fn collect_trait_bounds(trait_generics:&Generics, param_ident:&Ident) -> Vec<syn::Path>
+    {
+        let inline_bounds = trait_generics.type_params().filter(|type_param| type_param.ident == *param_ident).flat_map(
+            |type_param| type_param.bounds.iter()
+        );
+
+        let where_bounds = trait_generics.where_clause.iter().flat_map(|where_clause| where_clause.predicates.iter()).filter_map(
+            |predicate| match predicate {
+                syn::WherePredicate::Type(type_predicate) => match &type_predicate.bounded_ty {
+                    Type::Path(bounded) if bounded.qself.is_none() && bounded.path.is_ident(param_ident) => Some(type_predicate.bounds.iter()),
+                    _ => None
+                },
+                _ => None
+            }
+        ).flatten();
+
+        inline_bounds.chain(where_bounds).filter_map(|bound| match bound {
+            syn::TypeParamBound::Trait(trait_bound) => {
+                // associated type bindings (`Iterator<Item = T>`) aren't allowed in qualified paths
+                let mut bound_path = trait_bound.path.clone();
+                if let Some(syn::PathArguments::AngleBracketed(angle_args)) = bound_path.segments.last_mut().map(|segment| &mut segment.arguments) {
+                    angle_args.args = angle_args.args.iter().filter(|arg| !matches!(
+                        arg, GenericArgument::AssocType(_) | GenericArgument::AssocConst(_) | GenericArgument::Constraint(_)
+                    )).cloned().collect();
+                }
+                if let Some(last_segment) = bound_path.segments.last_mut() {
+                    if matches!(&last_segment.arguments, syn::PathArguments::AngleBracketed(angle_args) if angle_args.args.is_empty()) {
+                        last_segment.arguments = syn::PathArguments::None;
+                    }
+                }
+                Some(bound_path)
+            },
+            _ => None
+        }).collect()
+    }
+
+    fn find_type(&self, ident:&Ident) -> Option<&Type>
+    {
+        self.types.iter().find(|(param, _)| param == ident).map(|(_, arg)| arg)
+    }
+
+    fn find_constant(&self, ident:&Ident) -> Option<&Expr>
+    {
+        self.constants.iter().find(|(param, _)| param == ident).map(|(_, arg)| arg)
+    }
+}
+
+impl Fold for TraitArgsMapping
+{
+    fn fold_lifetime(&mut self, lifetime: Lifetime) -> Lifetime {
+        self.lifetimes.iter().find(|(param, _)| *param == lifetime).map_or(lifetime, |(_, arg)| arg.clone())
+    }
+
+    fn fold_type(&mut self, ty: Type) -> Type {
+        if let Type::Path(tpath) = &ty {
+            let first_segment = tpath.path.segments.first();
+            let replacement = first_segment.filter(
+                |segment| tpath.qself.is_none() && tpath.path.leading_colon.is_none() && segment.arguments.is_empty()
+            ).and_then(|segment| self.find_type(&segment.ident)).cloned();
+
+            match replacement {
+                // T
+                Some(type_arg) if tpath.path.segments.len() == 1 => { return type_arg; },
+                // T::Assoc becomes <Arg as Bound>::Assoc, when the parameter has a single trait bound
+                Some(type_arg) => {
+                    let param_ident = tpath.path.segments[0].ident.clone();
+                    let bound_path = self.type_bounds.iter().find(|(param, _)| *param == param_ident).and_then(
+                        |(_, bounds)| if bounds.len() == 1 {bounds.first().cloned()} else {None}
+                    ).map(|bound_path| self.fold_path(bound_path));
+
+                    let mut projection_path = bound_path.clone().unwrap_or_else(|| syn::Path{
+                        leading_colon:Some(Default::default()),
+                        segments:Default::default()
+                    });
+                    let position = projection_path.segments.len();
+                    // the argument is already concrete, only the projected segments are substituted
+                    for segment in tpath.path.segments.iter().skip(1) {
+                        let segment = self.fold_path_segment(segment.clone());
+                        projection_path.segments.push(segment);
+                    }
+
+                    return Type::Path(syn::TypePath{
+                        qself:Some(syn::QSelf{
+                            lt_token:Default::default(),
+                            ty:Box::new(type_arg),
+                            position,
+                            as_token:bound_path.map(|_| Default::default()),
+                            gt_token:Default::default()
+                        }),
+                        path:projection_path
+                    });
+                },
+                None => {}
+            }
+        }
+
+        syn::fold::fold_type(self, ty)
+    }
+
+    fn fold_generic_argument(&mut self, arg: GenericArgument) -> GenericArgument {
+        // constant parameters passed as arguments are parsed as types
+        if let GenericArgument::Type(Type::Path(tpath)) = &arg {
+            if let Some(const_arg) = tpath.path.get_ident().filter(|_| tpath.qself.is_none()).and_then(|ident| self.find_constant(ident)) {
+                return GenericArgument::Const(const_arg.clone());
+            }
+        }
+
+        syn::fold::fold_generic_argument(self, arg)
+    }
+
+    fn fold_expr(&mut self, expr: Expr) -> Expr {
+        if let Expr::Path(epath) = &expr {
+            if let Some(const_arg) = epath.path.get_ident().filter(|_| epath.qself.is_none()).and_then(|ident| self.find_constant(ident)) {
+                return const_arg.clone();
+            }
+        }
+
+        syn::fold::fold_expr(self, expr)
+    }
+}
+
+/// Collects the lifetimes of the trait arguments
+struct LifetimeCollector
+{
+    lifetimes:Vec<Lifetime>
+}
+
+impl Fold for LifetimeCollector
+{
+    fn fold_lifetime(&mut self, lifetime: Lifetime) -> Lifetime {
+        if !self.lifetimes.contains(&lifetime) {
+            self.lifetimes.push(lifetime.clone());
+        }
+        lifetime
+    }
+}
+
+/// Lifetimes named in the trait arguments that aren't declared by the implementing type,
+/// so they have to be declared in the `impl` header (`impl<'a> Parser<'a, Token> for Lexer`).
+pub(crate) fn undeclared_lifetimes(trait_args:&PathArguments, generics:&Generics) -> Vec<Lifetime>
+{
+    let mut collector = LifetimeCollector{lifetimes:Vec::new()};
+    collector.fold_path_arguments(trait_args.clone());
+    collector.lifetimes.into_iter().filter(|lifetime| {
+        lifetime.ident != "static" && lifetime.ident != "_" &&
+        !generics.lifetimes().any(|declared| declared.lifetime == *lifetime)
+    }).collect()
+}
+
+/// Detects the type parameters of the implementing type inside another type
+struct TypeParamFinder<'a>
+{
+    params:&'a [Ident],
+    found:bool
+}
+
+impl Fold for TypeParamFinder<'_>
+{
+    fn fold_type_path(&mut self, tpath: syn::TypePath) -> syn::TypePath {
+        let first_ident = tpath.path.segments.first().map(|segment| &segment.ident);
+        if tpath.qself.is_none() && tpath.path.leading_colon.is_none() && first_ident.is_some_and(|ident| self.params.contains(ident)) {
+            self.found = true;
+            return tpath;
+        }
+
+        syn::fold::fold_type_path(self, tpath)
+    }
+}
+
+/// Checks if `ty` depends on any of the type parameters `params`
+pub(crate) fn type_mentions_params(ty:&Type, params:&[Ident]) -> bool
+{
+    let mut finder = TypeParamFinder{params, found:false};
+    finder.fold_type(ty.clone());
+    finder.found
+}
@@ -0,0 +1,270 @@
+/*
+Hereditary
+Autors: Francisco Leon <https://github.com/superoptimo>
+License Apache-2.0
+*/
+
+use proc_macro2::token_stream::TokenStream as TkStream;
+use quote::ToTokens;
+use syn::{Ident, Signature};
+
+use crate::method_member_adapter::{
+    ForwardingMember, MethodAdaptError, MethodSignatureParts, ReceiverKind, TraitMethodImplMacros, generate_component_call
+};
+use crate::forwarding_options::{ForwardingOptions, FORWARD_FALLBACK_DEFAULT};
+use crate::self_type_mapping::{type_mentions_self, generate_output_rebuild};
+
+mod kw_variants
+{
+    syn::custom_keyword!(variants);
+    syn::custom_keyword!(only);
+    syn::custom_keyword!(fallback);
+}
+
+/// How an enum variant handles the methods of a forwarded trait
+#[derive(Clone)]
+pub enum VariantHandling
+{
+    /// Forwards to a field of the variant. `single` tells that it's the only field of the variant,
+    /// so the variant can be rebuilt from it.
+    Forward{field:Box<ForwardingMember>, single:bool},
+    /// The variant doesn't implement the trait, and its methods follow a fallback (`default` or `panic`)
+    Fallback(syn::Path)
+}
+
+/// Variant of an enum that dispatches the methods of a trait.
+///
+/// Its syntax is `Variant => field: Type`, `Variant => only field: Type` or `Variant => fallback(panic)`.
+#[derive(Clone)]
+pub struct VariantArm
+{
+    pub variant:Ident,
+    pub handling:VariantHandling
+}
+
+impl syn::parse::Parse for VariantArm
+{
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let variant:Ident = input.parse()?;
+        let _arrow = input.parse::<syn::Token![=>]>()?;
+        let handling = if input.peek(kw_variants::fallback) {
+            let _fallback = input.parse::<kw_variants::fallback>()?;
+            let fallback_content;
+            let _parens = syn::parenthesized!(fallback_content in input);
+            VariantHandling::Fallback(fallback_content.parse()?)
+        }
+        else {
+            let single = input.parse::<Option<kw_variants::only>>()?.is_some();
+            VariantHandling::Forward{field:Box::new(input.parse()?), single}
+        };
+
+        Ok(Self{variant, handling})
+    }
+}
+
+impl quote::ToTokens for VariantArm
+{
+    fn to_tokens(&self, tokens: &mut TkStream) {
+        let variant = &self.variant;
+        match &self.handling {
+            VariantHandling::Forward{field, single:true} => tokens.extend(quote::quote!(#variant => only #field)),
+            VariantHandling::Forward{field, single:false} => tokens.extend(quote::quote!(#variant => #field)),
+            VariantHandling::Fallback(fallback) => tokens.extend(quote::quote!(#variant => fallback(#fallback)))
+        }
+    }
+}
+
+/// Component that receives the forwarded methods: a member of a struct, or the variants of an enum.
+///
+/// Its syntax is the one of [`ForwardingMember`], or a list of [`VariantArm`] inside a `variants[]` block.
+#[derive(Clone)]
+pub enum ForwardingTarget
+{
+    Member(Box<ForwardingMember>),
+    Variants(Vec<VariantArm>)
+}
+
+impl ForwardingTarget
+{
+    /// Description of the target for diagnostics and documentation
+    pub fn describe(&self) -> String
+    {
+        match self {
+            Self::Member(member) => format!("the field `{}`", member.member_name()),
+            Self::Variants(_) => "the enum variants".to_string()
+        }
+    }
+}
+
+impl syn::parse::Parse for ForwardingTarget
+{
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.peek(kw_variants::variants) && input.peek2(syn::token::Bracket) {
+            let _variants = input.parse::<kw_variants::variants>()?;
+            let arms_content;
+            let _brackets = syn::bracketed!(arms_content in input);
+            let arms = arms_content.parse_terminated(VariantArm::parse, syn::Token![,])?;
+            Ok(Self::Variants(arms.into_iter().collect()))
+        }
+        else {
+            Ok(Self::Member(Box::new(input.parse()?)))
+        }
+    }
+}
+
+impl quote::ToTokens for ForwardingTarget
+{
+    fn to_tokens(&self, tokens: &mut TkStream) {
+        match self {
+            Self::Member(member) => member.to_tokens(tokens),
+            Self::Variants(arms) => tokens.extend(quote::quote!(variants[#(#arms),*]))
+        }
+    }
+}
+
+/// Information shared by the methods of a trait that are dispatched on the enum variants
+pub(crate) struct VariantDispatchContext<'a>
+{
+    pub(crate) arms:&'a [VariantArm],
+    pub(crate) trait_path:&'a syn::Path
+}
+
+impl VariantDispatchContext<'_>
+{
+    /// Generates the method that matches the receiver with every variant, and forwards the call
+    /// to the designated field of the variant, or follows its fallback.
+    pub(crate) fn create_method(
+        &self,
+        method_sig:&Signature,
+        base_hash:&str
+    ) -> Result<TraitMethodImplMacros, MethodAdaptError>
+    {
+        let method_name = &method_sig.ident;
+        let signature_parts = MethodSignatureParts::extract(method_sig)?;
+
+        // Variant fields are bound through match ergonomics, so only plain receivers are supported
+        let receiver_kind = match signature_parts.receiver {
+            Some((_, kind @ (ReceiverKind::Ref | ReceiverKind::RefMut | ReceiverKind::Value))) => kind,
+            Some(_) => {
+                return Err(MethodAdaptError::Unforwardable(method_name.span(), format!(
+                    "Method `{}` cannot be dispatched on enum variants, it requires a `self`, `&self` or `&mut self` receiver.",
+                    method_name
+                )));
+            },
+            None => {
+                return Err(MethodAdaptError::Unforwardable(method_name.span(), format!(
+                    "Associated function `{}` cannot be dispatched on enum variants, as it doesn't have a receiver.",
+                    method_name
+                )));
+            }
+        };
+
+        if signature_parts.parameter_types().any(type_mentions_self) {
+            return Err(MethodAdaptError::Unforwardable(method_name.span(), format!(
+                "Method `{}` takes an argument of type `Self`, which cannot be dispatched on enum variants.",
+                method_name
+            )));
+        }
+
+        let parameters:Vec<TkStream> = signature_parts.parameter_ids.iter().map(|param_id| param_id.to_token_stream()).collect();
+
+        let match_arms = self.arms.iter().map(
+            |arm| self.generate_match_arm(arm, method_sig, receiver_kind, &parameters)
+        ).collect::<Result<Vec<TkStream>, _>>()?;
+
+        // arguments are unused when every variant falls back
+        let unused_params = if self.arms.iter().all(|arm| matches!(arm.handling, VariantHandling::Fallback(_))) {
+            Some(quote::quote!(let _ = (#(&#parameters,)*);))
+        } else {None};
+
+        let forward_call = quote::quote!({
+            #unused_params
+            match $self_token {
+                #(#match_arms)*
+            }
+        });
+
+        let arms = self.arms;
+        Ok(TraitMethodImplMacros::assemble(
+            method_sig,
+            &signature_parts,
+            forward_call,
+            "the enum variants",
+            self.trait_path,
+            &quote::quote!(variants[#(#arms),*]),
+            base_hash
+        ))
+    }
+
+    fn generate_match_arm(
+        &self,
+        arm:&VariantArm,
+        method_sig:&Signature,
+        receiver_kind:ReceiverKind,
+        parameters:&[TkStream]
+    ) -> Result<TkStream, MethodAdaptError>
+    {
+        let method_name = &method_sig.ident;
+        let variant = &arm.variant;
+        let (field, single) = match &arm.handling {
+            VariantHandling::Forward{field, single} => (field, *single),
+            VariantHandling::Fallback(fallback) => {
+                let fallback_body = if fallback.is_ident(FORWARD_FALLBACK_DEFAULT) {
+                    quote::quote!(::core::default::Default::default())
+                }
+                else {
+                    let panic_message = format!(
+                        "variant `{}` doesn't implement `{}::{}`",
+                        variant, self.trait_path.to_token_stream().to_string().replace(' ', ""), method_name
+                    );
+                    quote::quote!(::core::panic!("{}", #panic_message))
+                };
+                return Ok(quote::quote!(Self::#variant{..} => { #fallback_body },));
+            }
+        };
+
+        // the field is bound as a reference for borrowing receivers,
+        // and fields stored behind a pointer are dereferenced
+        let member = &field.member;
+        let peeled_ty = receiver_kind.peeled_member_type(field);
+        let field_arg = match (receiver_kind, peeled_ty) {
+            (ReceiverKind::Ref, Some(_)) => quote::quote!(&**__variant_field),
+            (ReceiverKind::RefMut, Some(_)) => quote::quote!(&mut **__variant_field),
+            (_, Some(_)) => quote::quote!(*__variant_field),
+            (_, None) => quote::quote!(__variant_field)
+        };
+
+        let component_ty = receiver_kind.generate_component_type(field, &ForwardingOptions::default());
+        let field_call = generate_component_call(self.trait_path, component_ty.as_ref(), field_arg, method_sig, parameters);
+
+        // methods that return `Self` rebuild the variant from the value of its only field
+        let output_ty = match &method_sig.output {
+            syn::ReturnType::Type(_, output_ty) if type_mentions_self(output_ty) => output_ty,
+            _ => { return Ok(quote::quote!(Self::#variant{#member:__variant_field, ..} => #field_call,)); }
+        };
+
+        let reboxing = match (peeled_ty, &field.member_ty) {
+            (None, _) => Some(None),
+            (Some(_), Some(syn::Type::Path(tpath))) if tpath.path.segments.last().is_some_and(|seg| seg.ident == "Box") => {
+                Some(Some(quote::quote!(::std::boxed::Box::new)))
+            },
+            _ => None
+        };
+
+        let rebuild_error = MethodAdaptError::Unforwardable(method_name.span(), format!(
+            "Method `{}` returns `Self`, but the variant `{}` cannot be rebuilt from its field `{}`, as it requires being the only field of the variant, stored by value or in a `Box`.",
+            method_name, variant, field.member_name()
+        ));
+
+        let reboxing = match reboxing {
+            Some(reboxing) if single => reboxing,
+            _ => { return Err(rebuild_error); }
+        };
+
+        let output_rebuild = generate_output_rebuild(
+            output_ty, field_call, &|component_value| quote::quote!(Self::#variant{#member:#reboxing(#component_value)})
+        ).ok_or(rebuild_error)?;
+
+        Ok(quote::quote!(Self::#variant{#member:__variant_field, ..} => #output_rebuild,))
+    }
+}
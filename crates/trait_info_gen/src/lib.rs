@@ -11,7 +11,7 @@ mod simple_trait_info;
 mod trait_path_attrib;
 
 /// exports
-pub use simple_trait_info::SimpleTraitInfo;
+pub use simple_trait_info::{SimpleTraitInfo, TraitConstant};
 /// Utility for handling trait paths
 pub use trait_path_attrib::TraitPathAttrib;
 
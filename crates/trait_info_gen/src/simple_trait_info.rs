@@ -6,9 +6,43 @@ License Apache-2.0
 
 use proc_macro2::token_stream::TokenStream as TkStream;
 use quote::ToTokens;
-use syn::{ItemTrait, TraitItem, Generics, Ident, Signature};
+use syn::{ItemTrait, TraitItem, Generics, Ident, Signature, Type};
 use sha3::{Digest, Sha3_256};
 use base32ct::{Base32Unpadded, Encoding};
+
+/// Associated constant of a trait, with its type (`NAME: Type`).
+/// 
+/// The type is optional in the syntax, but it's required for declaring the constant in a trait implementation.
+#[derive(Clone)]
+pub struct TraitConstant
+{
+    pub ident: Ident,
+    pub ty: Option<Type>
+}
+
+impl quote::ToTokens for TraitConstant
+{
+    fn to_tokens(&self, tokens: &mut TkStream) {
+        self.ident.to_tokens(tokens);
+        if let Some(const_ty) = &self.ty {
+            tokens.extend(quote::quote!(: #const_ty));
+        }
+    }
+}
+
+impl syn::parse::Parse for TraitConstant
+{
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let ident:Ident = input.parse()?;
+        let ty = if input.peek(syn::Token![:]) {
+            let _colon = input.parse::<syn::Token![:]>()?;
+            Some(input.parse::<Type>()?)
+        } else {None};
+
+        Ok(TraitConstant{ident, ty})
+    }
+}
+
 pub struct SimpleTraitInfo
 {
     pub unsafety: bool,
@@ -16,7 +50,7 @@ pub struct SimpleTraitInfo
     pub generics: Generics,
     pub functions: Vec<Signature>,
     pub typedefs: Vec<Ident>,
-    pub constants: Vec<Ident>
+    pub constants: Vec<TraitConstant>
 }
 
 impl SimpleTraitInfo
@@ -25,7 +59,7 @@ impl SimpleTraitInfo
     {
         let mut tr_funcs:Vec<Signature> = Vec::new();
         let mut tr_types:Vec<Ident> = Vec::new();
-        let mut tr_consts:Vec<Ident> = Vec::new();
+        let mut tr_consts:Vec<TraitConstant> = Vec::new();
 
         traitinfo.items.iter().for_each(|tr_item|{
             match tr_item {
@@ -36,7 +70,7 @@ impl SimpleTraitInfo
                     tr_types.push(trty.ident.clone());
                 },
                 TraitItem::Const(trconst) => {
-                    tr_consts.push(trconst.ident.clone());
+                    tr_consts.push(TraitConstant{ident:trconst.ident.clone(), ty:Some(trconst.ty.clone())});
                 },
                 _ => {}
             };
@@ -80,8 +114,10 @@ impl quote::ToTokens for SimpleTraitInfo
         let trait_types = &self.typedefs;
         let trait_constants = &self.constants;
 
+        let trait_where = &self.generics.where_clause;
+
         let trait_tokens = quote::quote!{
-            #unsafekey #trait_name #trait_generics {
+            #unsafekey #trait_name #trait_generics #trait_where {
                 FUNCS[
                     #(#trait_funcs;)*
                 ]
@@ -125,7 +161,7 @@ impl std::fmt::Display for SimpleTraitInfo
         writeln!(f,"    CONSTANTS[")?;
 
         for ci in &self.constants {            
-            writeln!(f,"        {};", ci.to_string())?;
+            writeln!(f,"        {};", ci.to_token_stream())?;
         }
 
         writeln!(f,"    ]")?;
@@ -143,6 +179,8 @@ mod trait_inner
     pub type FuncsList = syn::punctuated::Punctuated<syn::Signature, syn::Token![;]>;
 
     pub type IdentList = syn::punctuated::Punctuated<syn::Ident, syn::Token![;]>;
+
+    pub type ConstantsList = syn::punctuated::Punctuated<super::TraitConstant, syn::Token![;]>;
 }
 
 
@@ -151,7 +189,8 @@ impl syn::parse::Parse for SimpleTraitInfo
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         let unsafe_key:Option<syn::token::Unsafe>  = input.parse()?;
         let trait_name:Ident = input.parse()?;
-        let trait_generics:Generics = input.parse()?;
+        let mut trait_generics:Generics = input.parse()?;
+        trait_generics.where_clause = input.parse()?;
         let trait_content;
         let _openbrace0 = syn::braced!(trait_content in input);
 
@@ -173,8 +212,8 @@ impl syn::parse::Parse for SimpleTraitInfo
         let _ty_kw = trait_content.parse::<trait_inner::CONSTANTS>()?;
         let constants_content;
         let _openbrace3 = syn::bracketed!(constants_content in trait_content);
-        let constants_list = trait_inner::IdentList::parse_terminated(&constants_content)?;
-        let constants_list:Vec<Ident> = constants_list.into_iter().collect();
+        let constants_list = trait_inner::ConstantsList::parse_terminated(&constants_content)?;
+        let constants_list:Vec<TraitConstant> = constants_list.into_iter().collect();
 
         syn::Result::Ok(SimpleTraitInfo{
             unsafety : unsafe_key.is_some(),
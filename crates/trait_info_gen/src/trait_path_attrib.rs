@@ -37,6 +37,16 @@ impl TraitPathAttrib
         }
     }
 
+    /// generates the original path, including the generic arguments of the trait
+    pub fn gen_full_path(&self) -> syn::Path
+    {
+        let mut fullpath = self.gen_path();
+        if let Some(last_segment) = fullpath.segments.last_mut() {
+            last_segment.arguments = self.trait_path_args.clone();
+        }
+        fullpath
+    }
+
     /// Generates a path with the last identifier is named
     /// `TraitInfo_<TraitName>`
     pub fn gen_info_macro_path(&self) -> TkStream
@@ -18,6 +18,7 @@ impltrait[
     }
 ];
 submember_id;
+options[];
 traitdef[
      CashFlow {
         FUNCS[
@@ -35,8 +36,9 @@ const TRAIT_INFO_CODE2: &'static str = "
 header[
     MyStruct<'b,B:bound, C> where C:'b + bound
 ];
-submember_id;
+submember_id: SubmemberType;
 traitpath[momo::CashFlow];
+options[];
 traitdef[
      CashFlow {
         FUNCS[
@@ -73,6 +75,7 @@ impltrait[
     }
 ];
 submember_id;
+options[];
 traitdef[
      CashFlow {
         FUNCS[
@@ -94,8 +97,9 @@ const TRAIT_INFO_CODE4: &'static str = "
 header[
     MyStruct<'b,B:bound, C> where C:'b + bound
 ];
-submember_id;
+submember_id: SubmemberType;
 traitpath[module::CashFlow];
+options[];
 traitdef[
      CashFlow<'b, B:bound> {
         FUNCS[
@@ -114,8 +118,9 @@ const TRAIT_INFO_CODE5: &'static str = "
 header[
     MyStruct<'b,B:bound, C> where C:'b + bound
 ];
-submember_id;
+submember_id: SubmemberType;
 traitpath[::ops::CashFlow];
+options[];
 traitdef[
      CashFlow {
         FUNCS[
@@ -0,0 +1,67 @@
+/*
+Hereditary
+Autors: Francisco Leon <https://github.com/superoptimo>
+License Apache-2.0
+*/
+
+use std::sync::{Arc, PoisonError, RwLock};
+
+/// Shared slot of a component, whose `Arc` can be swapped at runtime from any thread.
+///
+/// It's the field type of the thread-safe [swappable](macro@crate::Forwarding#swappable-fields) components,
+/// like `ArcSlot<dyn Strategy + Send + Sync>`. The forwarded methods call a snapshot of the component taken by
+/// [`load`](ArcSlot::load), so a swap doesn't disturb the calls in progress, which finish on the previous component.
+///
+/// The `Arc` is guarded by a `RwLock` that is only held while it's cloned or replaced, and the previous component is
+/// dropped after releasing the lock.
+pub struct ArcSlot<T:?Sized>
+{
+    current:RwLock<Arc<T>>
+}
+
+impl<T:?Sized> ArcSlot<T>
+{
+    pub fn new(component:Arc<T>) -> Self
+    {
+        Self{current:RwLock::new(component)}
+    }
+
+    /// Snapshot of the current component
+    pub fn load(&self) -> Arc<T>
+    {
+        // the lock cannot be poisoned while the `Arc` is inconsistent, as cloning or replacing it doesn't panic
+        self.current.read().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+
+    /// Replaces the component, which receives the calls that start afterwards.
+    pub fn store(&self, component:Arc<T>)
+    {
+        drop(self.swap(component));
+    }
+
+    /// Replaces the component, and returns the previous one.
+    pub fn swap(&self, component:Arc<T>) -> Arc<T>
+    {
+        let mut current = self.current.write().unwrap_or_else(PoisonError::into_inner);
+        std::mem::replace(&mut *current, component)
+    }
+
+    pub fn into_inner(self) -> Arc<T>
+    {
+        self.current.into_inner().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T:?Sized> From<Arc<T>> for ArcSlot<T>
+{
+    fn from(component: Arc<T>) -> Self {
+        Self::new(component)
+    }
+}
+
+impl<T:?Sized + std::fmt::Debug> std::fmt::Debug for ArcSlot<T>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ArcSlot").field(&self.load()).finish()
+    }
+}
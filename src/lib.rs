@@ -194,13 +194,16 @@ pub use trait_info::trait_info as trait_info;
 /// struct members (designated by the `#[forward_derive]` attribute)
 /// as they bring the required implementation of those traits.
 /// 
-/// This macro brings the full list of trait methods implemented for the subcomponent member, but 
-/// it requires that the instanced traits were declared as [`object safe`](https://doc.rust-lang.org/reference/items/traits.html#object-safety)
-/// exclusively: That means that they only contain dispatchable  methods and cannot include generics, constants or associated type items in
-/// their original trait declaration.
+/// This macro brings the full list of trait methods implemented for the subcomponent member. Traits don't need to be
+/// [`object safe`](https://doc.rust-lang.org/reference/items/traits.html#object-safety): generic methods keep their generics,
+/// methods bounded by `where Self: Sized` are forwarded as any other method, and associated functions are called on the field type.
+/// Associated types and constants are declared with the ones of the field type, as `type Item = <FieldTy as Trait>::Item;`
+/// (enums take them from the first variant that forwards the trait).
+/// When some methods cannot be forwarded (for instance, a method that returns `Self` without a `rewrap` option), 
+/// the derive reports a single error that lists all of them.
 /// 
-/// Otherwise, If there is need to extend the functionality of more complex traits that aren't purely `object-safe` 
-/// (with associated items and generic arguments), consider using [`forward_trait`](macro@forward_trait) instead.
+/// Otherwise, If there is need to extend the functionality of more complex traits
+/// (with associated items that differ from the field ones), consider using [`forward_trait`](macro@forward_trait) instead.
 /// 
 /// Anyhow, instanced traits should incorporate their previously declared trait signature representation 
 /// via [`trait_info`](macro@trait_info) macro.
@@ -246,7 +249,163 @@ pub use trait_info::trait_info as trait_info;
 /// #[forward_derive(Trait1, Trait2...<list of traits>)
 /// field:Type
 /// ```
-/// ### `forwarding_mod_path` 
+/// Fields of tuple structs and newtypes can be designated too, so their methods are forwarded to `self.0`, `self.1`, etc:
+/// ```text
+/// struct Meters(#[forward_derive(Measure)] Length);
+/// ```
+/// When the type of the field depends on the generic parameters of the struct, the derived implementation is bounded by
+/// `FieldTy: Trait`, so generic wrappers don't need declaring that bound themselves:
+/// ```text
+/// struct Logged<T> {
+///     #[forward_derive(Writer)] // impl<T> Writer for Logged<T> where T: Writer
+///     inner:T,
+/// }
+/// ```
+/// Generic traits take their arguments in the attribute, and the signatures of the trait are declared with them.
+/// Arguments can use the generics of the struct, and lifetimes that the struct doesn't declare are declared by the implementation:
+/// ```text
+/// #[forward_derive(Parser<'src, char>, Encode<u16, 2>)]
+/// cursor:Cursor
+/// ```
+/// Forwarding options can be appended as `key = value` entries, and they apply to every trait listed in the attribute. 
+/// For example, `#[forward_derive(Syncing, arc_project = project_fn)]` forwards the `self: Arc<Self>` methods through
+/// a projection function (see the [receivers supported by `forward_trait`](macro@forward_trait#receivers)), and 
+/// `#[forward_derive(Factory, construct = default)]` forwards [associated functions](macro@forward_trait#associated-functions)
+/// that build the composite, while `#[forward_derive(Cloning, rewrap = clone)]` forwards methods that
+/// [return `Self`](macro@forward_trait#returning-self).
+/// 
+/// The forwarded methods can be selected by appending `except(..)` or `only(..)` to the trait. The methods that aren't forwarded
+/// are implemented by a partial implementation of the trait, declared after the struct with [`forward_partial`]:
+/// ```text
+/// #[derive(Forwarding)]
+/// struct Hybrid
+/// {
+///     #[forward_derive(Cannis(except(roam)))]
+///     animal:Dog
+/// }
+/// 
+/// #[forward_partial]
+/// impl Cannis for Hybrid {
+///     fn roam(&mut self) { self.animal.roam(); self.animal.roam(); }
+/// }
+/// ```
+/// Instead, the methods that aren't forwarded can designate the inherent methods of the struct that implement them as
+/// `method = inherent_method`, in `except` or after the forwarded methods of `only`, so the trait doesn't need a partial implementation:
+/// ```text
+/// #[forward_derive(Cannis(except(roam = wander, position = current_position)), Bird(only(fly, land = glide_down)))]
+/// animal:Hybrid
+/// ```
+/// The inherent methods have to be named differently from the trait methods, as `Self::roam` would resolve to the trait method
+/// itself when the struct doesn't declare it:
+/// ```compile_fail
+/// # extern crate hereditary as other_hereditary;
+/// # mod hereditary {
+/// #    pub use trait_info::trait_info as trait_info;
+/// #    pub use forwarding::Forwarding as Forwarding;
+/// #    pub use forwarding::ForwardingDeriveMemberProcess as ForwardingDeriveMemberProcess; }
+/// #[hereditary::trait_info]
+/// trait Cannis
+/// {
+///     fn roam(&mut self);
+///     fn position(&self) -> u32;
+/// }
+/// # struct Dog{steps:u32}
+/// # impl Cannis for Dog {
+/// #    fn roam(&mut self) { self.steps += 1; }
+/// #    fn position(&self) -> u32 { self.steps } }
+///
+/// #[derive(hereditary::Forwarding)]
+/// struct Hybrid
+/// {
+///     // error: `roam` cannot be implemented by the trait method itself
+///     #[forward_derive(Cannis(except(roam = roam)))]
+///     animal:Dog
+/// }
+/// ```
+/// 
+/// Methods can also be forwarded to differently named methods of the field with the [`map(..)` option](macro@forward_trait#method-mappings),
+/// as in `#[forward_derive(Shape, map(area = surface))]`. As the mapped methods have to be declared by the forwarded trait,
+/// traits with different mappings are declared in separate `forward_derive` attributes.
+/// Likewise, `#[forward_derive(Bird, route(altitude = physics))]` forwards some methods to [other fields](macro@forward_trait#method-routes)
+/// of the struct, which take their declared types.
+/// Fields with interior mutability are forwarded through the guards of their cells with the [`cell` option](macro@forward_trait#interior-mutability),
+/// as in `#[forward_derive(Counter, cell = mutex)]` for an `Arc<Mutex<Tally>>` field, whose derived associated items and bounds take the
+/// component type `Tally`.
+/// Fields that can be missing are forwarded with the [`optional` option](macro@forward_trait#optional-members), as in
+/// `#[forward_derive(Gauge, optional = option, fallback = default)]` for an `Option<Box<dyn Gauge>>` field, and components
+/// built on first use are forwarded with the [`lazy` option](macro@forward_trait#lazy-members), as in
+/// `#[forward_derive(Lookup, lazy = build_index)]` for a `OnceCell<Index>` field.
+/// Collections of components receive every method with the [`broadcast` flag](macro@forward_trait#broadcast), as in
+/// `#[forward_derive(Shape, broadcast, aggregate(area = sum))]` for a `Vec<Box<dyn Shape>>` field.
+/// The [`chain(..)` option](macro@forward_trait#chains) tries the methods on the field and then on other fields of the struct,
+/// as in `#[forward_derive(Resolver, chain(disk, ..stubs))]`.
+/// 
+/// The list options like `map(..)` or `aggregate(..)` can be repeated, but any other option is set once per attribute:
+/// ```compile_fail
+/// # extern crate hereditary as other_hereditary;
+/// # mod hereditary {
+/// #    pub use trait_info::trait_info as trait_info;
+/// #    pub use forwarding::Forwarding as Forwarding;
+/// #    pub use forwarding::ForwardingDeriveMemberProcess as ForwardingDeriveMemberProcess; }
+/// #[hereditary::trait_info]
+/// trait Gauge
+/// {
+///     fn level(&self) -> u32;
+/// }
+/// # struct Meter{level:u32}
+/// # impl Gauge for Meter {
+/// #    fn level(&self) -> u32 { self.level } }
+///
+/// #[derive(hereditary::Forwarding)]
+/// struct Panel
+/// {
+///     // error: duplicate option `fallback`
+///     #[forward_derive(Gauge, optional = option, fallback = default, fallback = panic)]
+///     meter:Option<Meter>
+/// }
+/// ```
+/// 
+/// Unsafe traits have to be listed inside `unsafe(..)`, which generates an `unsafe impl` for the struct. By doing that, 
+/// the attribute acknowledges that the field upholds the safety contract of the trait on behalf of the composite:
+/// ```text
+/// #[forward_derive(unsafe(RawBuffer), Peek)]
+/// payload:Bytes
+/// ```
+/// ### Swappable fields
+/// The component of a field can be swapped at runtime with the `swappable` flag, for strategies or test doubles, when it's
+/// stored in a `Box`, `Rc` or `Arc`. Besides the trait implementation, the struct receives the inherent methods `set_<field>`,
+/// `replace_<field>` (which returns the previous component) and `with_<field>` (which returns the struct):
+/// ```text
+/// #[derive(Forwarding)]
+/// struct Checkout {
+///     #[forward_derive(Pricing, swappable)] // checkout.set_pricing(Box::new(Reduced{percent:10}))
+///     pricing:Box<dyn Pricing>,
+/// }
+/// ```
+/// These methods take `&mut self`, so composites shared between threads store their component in an [`ArcSlot`],
+/// whose methods swap the component through `&self`, as in `desk.set_quote(Arc::new(Reduced{percent:50}))` for
+/// an `ArcSlot<dyn Quote + Send + Sync>` field. The forwarded methods call a snapshot of the component in the slot,
+/// so they require a `&self` receiver and cannot return borrowed values.
+/// ### Enums
+/// `Forwarding` can be derived on enums as well, and then every forwarded method matches `self` and dispatches the
+/// call to the field of the current variant. Traits are listed on the enum, its variants or their fields:
+/// - Variants with a single field forward to it.
+/// - A field designated with `#[forward_derive(Trait)]` receives the methods for its variant.
+/// - Other variants follow the `fallback` option: `default` returns `Default::default()`, and `panic` panics.
+///   It can be declared on the enum, for every variant without a single field, or on a specific variant.
+/// ```text
+/// #[derive(Forwarding)]
+/// #[forward_derive(Shape, fallback = panic)]
+/// enum Figure {
+///     Round(Circle),
+///     Boxed{square:Box<Square>},
+///     Tiled(#[forward_derive(Shape)] Square, u32),
+///     Empty,
+/// }
+/// ```
+/// Only methods with `self`, `&self` or `&mut self` receivers can be dispatched, and methods that return `Self`
+/// require the field to be the only one of its variant, stored by value or in a `Box`, so the variant can be rebuilt from it.
+/// ### `forwarding_mod_path`
 /// Optional attribute that can be used in cases when there is a need for re-exporting `hereditary` module items.
 /// Because `Forwarding` generates declarative macros that depend on inner procedural macro functions
 /// (as those are meant to be invoked within the generated code), it just keeps the references to those inner functions 
@@ -292,8 +451,8 @@ pub use forwarding::Forwarding as Forwarding;
 /// }
 /// ```
 /// This attribute procedural macro can be used for extending the functionality of complex traits that aren't purely [`object safe`](https://doc.rust-lang.org/reference/items/traits.html#object-safety)
-/// (those declared traits with associated items and generic arguments). Nevertheless, it could only extend dispatchable methods with 
-/// `self` as receiver (reference and mutable ref).
+/// (those declared traits with associated items and generic arguments). Nevertheless, it could only extend methods with 
+/// `self` as receiver (see [Receivers](#receivers) below).
 /// 
 /// Also this macro allows to combine custom method implementations with the existing methods from the subcomponent instance.
 /// 
@@ -327,8 +486,343 @@ pub use forwarding::Forwarding as Forwarding;
 /// ends-up calling another inner procedural macro (`hereditary::ForwardingTraitImplProcess`) that processes the 
 /// syntax information for the required trait and then constructs the corresponding forwarding methods linked to the
 /// instancing field component.
+/// 
+/// ### Receivers
+/// Forwarded methods keep the receiver declared in the trait, and the designated field is adapted to it:
+/// - `&self` and `&mut self` borrow the field.
+/// - `self` moves the designated field out of the composite struct, and the remaining fields
+///   are dropped when the forwarded call returns (`Copy` fields are just copied). Because of that, by value methods cannot be forwarded on
+///   composite types that implement `Drop`, as Rust doesn't allow moving fields out of them (error `E0509`).
+/// - `self: Box<Self>` unboxes the composite and re-boxes the field.
+/// - `self: Rc<Self>` and `self: Arc<Self>` cannot move the field out of a shared pointer, so the field has to be
+///   stored as `Rc<T>` (or `Arc<T>`), and it gets cloned for the forwarded call. Alternatively, a projection function 
+///   can be provided with the options `rc_project` and `arc_project`, which receives the shared composite and returns the
+///   shared pointer of the component:
+/// ```text
+/// fn project_leader(crew:Arc<Crew>) -> Arc<Worker> { ... }
+/// 
+/// #[forward_trait(leader, arc_project = project_leader)]
+/// impl Syncing for Crew {}
+/// ```
+/// - `self: Pin<&mut Self>` and `self: Pin<&Self>` are forwarded with a safe structural pin projection to the field,
+///   without any `unsafe` code. Such projection is sound because it requires the field to be `Unpin` (and the composite 
+///   too, for mutable pins), so composing `poll`-like methods of futures and streams only needs that the components 
+///   which aren't `Unpin` were stored as `Pin<Box<T>>`. Such fields are projected into their pinned pointee with
+///   `Pin::as_ref` and `Pin::as_mut`, which requires annotating the field with its type (`#[forward_trait(timer: Pin<Box<Timer>>)]`).
+/// 
+/// The designated field can be a tuple index as well (`#[forward_trait(0)]`) for tuple structs and newtypes.
+/// 
+/// The field can also be annotated with its type (`#[forward_trait(leader: Worker)]`), so the macro reports
+/// a clear error when a shared receiver cannot be forwarded to it.
+/// 
+/// ### Fully qualified calls
+/// Forwarded methods call the trait method with its fully qualified path, `<FieldType as Trait>::method::<G...>(&self.field, args)`,
+/// so an inherent method of the field type, or a method of another trait with the same name, is never called instead. 
+/// The generic arguments of the method are passed explicitly, so generic parameters that only appear in the return type 
+/// are inferred from the composite method.
+/// 
+/// Fields stored behind a reference, `Box<T>`, `Rc<T>` or `Arc<T>` are dereferenced by borrowing methods, which call the
+/// trait method of the pointee, like `<dyn Engine as Trait>` for a field annotated as `engine: Box<dyn Engine>`.
+/// When the field isn't annotated with its type, the trait method is called with the method call syntax
+/// (`self.engine.method(args)`), whose auto-deref reaches the components behind a pointer, but may pick an inherent method
+/// with the same name. Annotate the field (`#[forward_trait(engine: Box<Engine>)]`), as `Forwarding` does for every field,
+/// to get the fully qualified call.
+/// 
+/// ### Associated functions
+/// Functions without receiver (like `fn kind() -> &'static str`) are forwarded as `<FieldType as Trait>::kind()`,
+/// so they require that the field is annotated with its type. Otherwise they are left to the trait implementation.
+/// 
+/// When the function returns `Self` (or `Option<Self>`, `Result<Self, E>`, `Vec<Self>` and tuples of them), the composite is built from the component value with the `construct` option:
+/// - `construct = default` assigns the component into a `Default::default()` instance of the composite.
+/// - `construct = path` calls a function that receives the component and returns the composite.
+/// ```text
+/// #[forward_trait(part: Gear, construct = Robot::from_gear)]
+/// impl Factory for Robot {}
+/// ```
+/// 
+/// ### Returning `Self`
+/// Methods with receiver that return `Self` (in the same forms as above) obtain an instance of the field type from the component,
+/// so the composite is rebuilt around it with the `rewrap` option:
+/// - `rewrap = clone` clones the receiver composite and replaces the designated field. It requires `Self: Clone`.
+/// - `rewrap = path` calls a function `fn(&Self, FieldType) -> Self`, which receives the original composite and the new component.
+/// 
+/// Methods that take `self` (or `self: Box<Self>`) and return a plain `Self` don't need the option, as the returned component is
+/// put back into the consumed composite. Other receivers that consume the composite (`Rc`, `Arc`, `Pin<&mut Self>`) clone it 
+/// before the call, so they also require `Self: Clone`.
+/// ```text
+/// fn with_sibling(crew:&Crew, leader:Worker) -> Crew { ... }
+/// 
+/// #[forward_trait(leader, rewrap = with_sibling)]
+/// impl Cloning for Crew {}
+/// ```
+/// 
+/// ### Asynchronous methods
+/// `async fn` methods are forwarded as `async fn` too, awaiting the future of the component, while methods that
+/// return `impl Future<Output = T> + Send` (or any other return-position `impl Trait`) keep their signature and bounds,
+/// returning the value of the component as it is. In both cases, the futures of the composite are `Send`
+/// whenever the futures of the component are.
+/// 
+/// ### Unsafe traits and methods
+/// Unsafe traits can only be forwarded with an `unsafe impl` declaration, which acknowledges that the designated field upholds
+/// the safety contract of the trait. Forwarded `unsafe fn` methods keep their qualifiers and call the field method inside an
+/// `unsafe` block, with a generated `# Safety` documentation section that points back to the field, since the caller
+/// of the composite method must uphold the same contract.
+/// ```text
+/// #[forward_trait(inner)]
+/// unsafe impl RawBuffer for Frame {}
+/// ```
+/// The `unsafe` block only contains the call of the field method, so the expressions given by the options, like accessors,
+/// mapped arguments or fallbacks, aren't placed in an unsafe context:
+/// ```compile_fail
+/// # extern crate hereditary as other_hereditary;
+/// # mod hereditary {
+/// #    pub use trait_info::trait_info as trait_info;
+/// #    pub use forwarding::forward_trait as forward_trait;
+/// #    pub use forwarding::ForwardingTraitImplProcess as ForwardingTraitImplProcess; }
+/// #[hereditary::trait_info]
+/// trait Peek
+/// {
+///     unsafe fn peek(&self) -> u8;
+/// }
+/// # struct Bytes{data:Vec<u8>}
+/// # impl Peek for Bytes {
+/// #    unsafe fn peek(&self) -> u8 { *self.data.get_unchecked(0) } }
+///
+/// struct Frame
+/// {
+///     parts:Vec<Bytes>
+/// }
+///
+/// // error: the unchecked access of the accessor requires its own `unsafe` block
+/// #[hereditary::forward_trait(via = self.parts.get_unchecked(0))]
+/// impl Peek for Frame {}
+/// ```
+/// 
+/// ### Arguments of type `Self`
+/// Arguments that receive another instance of the composite, like `other:&Self` in `fn distance(&self, other:&Self) -> f64`,
+/// are projected into the designated field of that instance (`&other.field`), so binary methods of comparison, merging
+/// or measuring traits are forwarded between the components. The supported forms are `Self`, `&Self`, `&mut Self`, 
+/// and `Option` or `Vec` of them. Arguments taken by value move the field out of the other instance, so the composite
+/// cannot implement `Drop`. Fields that store the component behind a pointer are dereferenced like the field of the receiver
+/// (`&*other.field`), so the component is only moved out of a `Box`, and only borrowed mutably from a `Box` or a `&mut` reference.
+/// 
+/// Slices like `&[Self]` cannot be projected without cloning the field of every element, so they are reported as unforwardable,
+/// and traits can take `Vec<&Self>` instead:
+/// ```compile_fail
+/// # extern crate hereditary as other_hereditary;
+/// # mod hereditary {
+/// #    pub use trait_info::trait_info as trait_info;
+/// #    pub use forwarding::Forwarding as Forwarding;
+/// #    pub use forwarding::ForwardingDeriveMemberProcess as ForwardingDeriveMemberProcess; }
+/// #[hereditary::trait_info]
+/// trait Metric
+/// {
+///     fn nearest(&self, others:&[Self]) -> Option<usize> where Self: Sized;
+/// }
+/// # struct Point{x:f64}
+/// # impl Metric for Point {
+/// #    fn nearest(&self, others:&[Self]) -> Option<usize> { others.iter().position(|other| other.x == self.x) } }
+///
+/// #[derive(hereditary::Forwarding)]
+/// struct City
+/// {
+///     // error: `others` cannot be projected into the field `location`
+///     #[forward_derive(Metric)]
+///     location:Point
+/// }
+/// ```
+/// 
+/// ### Method mappings
+/// The `map(..)` option forwards trait methods to differently named methods of the component, which can be inherent methods
+/// or methods of another trait. A mapping `method = component_method` passes the arguments in the same order, while
+/// `method(arg, ...) = component_method(expr, ...)` binds the arguments of the trait method to names that can be used
+/// in the argument expressions of the component call:
+/// ```text
+/// #[forward_trait(engine, map(area = surface, fly(elev) = ascend(elev, DEFAULT_GUST)))]
+/// impl Flyer for Kite {}
+/// ```
+/// Mapped methods are called with the method call syntax (`self.engine.ascend(..)`), so they support the `self`, `&self` and `&mut self`
+/// receivers, and associated functions are called on the declared type of the field. Mapped methods cannot return `Self`.
+/// 
+/// ### Method routes
+/// The `route(..)` option splits a trait implementation across several fields, by forwarding some methods to other fields
+/// of the composite. Routed fields can declare their type as `method = field: Type`, which is required for associated functions:
+/// ```text
+/// #[forward_trait(birdpart, route(altitude = physics))]
+/// impl Bird for KimeraSphinx {}
+/// ```
+/// Routed methods are called with method call syntax, as `self.physics.altitude()`, so the routed fields only have to declare
+/// the routed methods instead of implementing the whole trait. They follow the rest of the options as well,
+/// so `route(altitude = physics), map(altitude = height)` calls `self.physics.height()`.
+/// 
+/// ### Accessors
+/// Instead of a field, the component can be reached through an accessor expression with the `via` option, which covers
+/// nested components, components in tuples or arrays, and components behind getter methods of the composite. Separate accessors
+/// can be given for `&self` and `&mut self` methods with `via_ref` and `via_mut`, and accessors that don't start with `self`
+/// are members of the composite (`get()` calls `self.get()`):
+/// ```text
+/// #[forward_trait(via = self.parts.wing)]
+/// impl Wing for Eagle {}
+/// 
+/// #[forward_trait(via_ref = get(), via_mut = get_mut())]
+/// impl Wing for Phoenix {}
+/// ```
+/// Field paths are borrowed by the receiver (`&self.parts.wing`), while method calls are expected to return a reference or a
+/// smart pointer of the component, which is reborrowed (`&*self.get()`). Accessors don't support the receivers based on
+/// pointers to the composite, nor methods that take or return `Self`, and associated functions are only forwarded when the
+/// field is declared with its type.
+/// 
+/// ### Interior mutability
+/// Components stored in a `RefCell`, `Mutex` or `RwLock` (optionally behind a `Box`, `Rc` or `Arc`) are reached through
+/// the guard of their cell with the `cell` option, which takes the guard that fits each receiver:
+/// - `cell = refcell` calls `borrow()` for `&self` methods and `borrow_mut()` for `&mut self` methods.
+/// - `cell = mutex` calls `lock()` for both.
+/// - `cell = rwlock` calls `read()` for `&self` methods and `write()` for `&mut self` methods.
+/// 
+/// As the guards only need a shared borrow of the field, `&mut self` methods reach components shared by `Rc<RefCell<T>>`
+/// or `Arc<Mutex<T>>`, and `self` methods unwrap the cell with `into_inner()` when it's stored by value.
+/// Poisoned locks panic by default (`on_error = panic`), as well as conflicting `RefCell` borrows. Otherwise, `on_error = path`
+/// calls a function with the borrow or poison error, and the method returns its result:
+/// ```text
+/// fn busy<E:Display>(error:E) -> Result<u32, String> { Err(error.to_string()) }
+/// 
+/// #[forward_trait(tally: Rc<RefCell<Tally>>, cell = refcell, on_error = busy)]
+/// impl Tracker for Watched {}
+/// ```
+/// The guard is released when the method returns, so methods that return borrowed values (references, lifetimes or `impl Trait`)
+/// are reported as unforwardable, instead of failing with a borrow checker error. Methods that take or return `Self` aren't supported
+/// either. Re-entrant calls follow the semantics of the cell, so locking a `Mutex` again from the same thread deadlocks.
+/// 
+/// ### Optional members
+/// Components that can be missing are unwrapped on every call with `optional = option` for `Option` fields, or upgraded
+/// with `optional = weak` for `Weak` back-references. When the component is missing, the method follows the `fallback` option:
+/// - `fallback = default` returns `Default::default()` of the output type.
+/// - `fallback = path` returns the result of calling the function `path(self, args)`, which takes the receiver and the arguments of the method.
+/// - `fallback = panic` panics with a message that names the field and the method, which is also the default.
+/// 
+/// Single methods can take their own fallback with `method_fallback(method = fallback, ...)`:
+/// ```text
+/// fn unplugged(socket:&Socket) -> String { format!("unplugged {}", socket.outlet) }
+/// 
+/// #[forward_trait(gauge: Option<Box<dyn Gauge>>, optional = option, fallback = default, method_fallback(label = unplugged))]
+/// impl Gauge for Socket {}
+/// 
+/// #[forward_trait(gauge: Weak<RefCell<Meter>>, optional = weak, cell = refcell)]
+/// impl Gauge for Panel {}
+/// ```
+/// Upgraded `Weak` fields only give shared access to their component, so `&mut self` methods require the `cell` option, and
+/// methods that return borrowed values are unforwardable, as the upgraded pointer is dropped when the method returns.
+/// `Option` fields support the `self`, `&self` and `&mut self` receivers, and methods that take or return `Self` aren't supported.
+/// The default bodies of the trait cannot be used as fallbacks, as `trait_info` only records the signatures of the methods,
+/// so `fallback = trait_default` is rejected.
+/// 
+/// ### Lazy members
+/// Components that are built on first use are stored in a `OnceCell` or `OnceLock`, and the `lazy = init_fn` option
+/// initializes them with `init_fn` when a forwarded method needs them:
+/// - `&self` methods call `get_or_init(init_fn)`.
+/// - `&mut self` methods call `get_mut()` once the component is initialized.
+/// - `self` methods take the component with `into_inner()`, or build it when it's missing.
+/// 
+/// Fields of type `LazyCell` or `LazyLock` hold their initializer, so they are declared with `lazy = force` and
+/// dereferenced to force them, which supports the `&self` and `&mut self` methods:
+/// ```text
+/// #[forward_trait(index: OnceCell<Index>, lazy = build_index)]
+/// impl Lookup for Catalog {}
+/// 
+/// #[forward_trait(index: LazyCell<Index>, lazy = force)]
+/// impl Search for Archive {}
+/// ```
+/// Methods that take or return `Self` aren't supported by lazy members.
+/// 
+/// ### Broadcast
+/// Composites that hold a collection of components, like the children of a composite shape, forward every method to all the
+/// elements of the collection with the `broadcast` option. Sequences (`Vec`, `VecDeque`, `LinkedList`, arrays and slices) are
+/// iterated in order, and the values of maps (`HashMap` and `BTreeMap`) in the order of the map. Methods that don't return a value
+/// are called on each element, while the values returned by the elements are combined with the aggregator of the method in
+/// `aggregate(method = aggregator, ...)`:
+/// - `sum` adds the values.
+/// - `min` and `max` return the least or the greatest value of methods that return an `Option`. They skip the elements that return
+///   `None`, and return `None` when no element returns a value, like for empty collections.
+/// - `all` and `any` combine `bool` values.
+/// - `collect` concatenates the collections returned by the elements, like `Vec` or `String`.
+/// - `fold_fn` folds the values with a function `fn(Output, Output) -> Output`, starting with `Default::default()`.
+/// ```text
+/// // `fn widest(&self) -> Option<f64>`
+/// #[forward_trait(children: Vec<Box<dyn Shape>>, broadcast, aggregate(area = sum, names = collect, widest = max))]
+/// impl Shape for Group {}
+/// ```
+/// Methods that don't return an `Option` cannot be aggregated with `min` or `max`, as their empty collections have no value:
+/// ```compile_fail
+/// # extern crate hereditary as other_hereditary;
+/// # mod hereditary {
+/// #    pub use trait_info::trait_info as trait_info;
+/// #    pub use forwarding::forward_trait as forward_trait;
+/// #    pub use forwarding::ForwardingTraitImplProcess as ForwardingTraitImplProcess; }
+/// #[hereditary::trait_info]
+/// trait Gauge
+/// {
+///     fn level(&self) -> u32;
+/// }
+/// # struct Meter{level:u32}
+/// # impl Gauge for Meter {
+/// #    fn level(&self) -> u32 { self.level } }
+///
+/// struct Panel
+/// {
+///     meters:Vec<Meter>
+/// }
+///
+/// // error: `level` has to return `Option<u32>` to be aggregated with `max`
+/// #[hereditary::forward_trait(meters: Vec<Meter>, broadcast, aggregate(level = max))]
+/// impl Gauge for Panel {}
+/// ```
+/// The arguments of the method are cloned for each element, except mutable references, which are reborrowed. Methods that return
+/// a value without an aggregator are reported as unforwardable, as well as asynchronous methods that return a value, and methods
+/// that take or return `Self`.
+/// 
+/// ### Chains
+/// Handler, resolver and loader traits can try a method on several members in order, until one of them answers.
+/// The `chain(..)` option lists the members that are tried after the designated one, and `..member` tries the elements
+/// of a collection member in order. The designated member can be omitted, so the chain starts with its first link:
+/// ```text
+/// #[forward_trait(chain(cache, disk: Box<Table>, ..stubs: Vec<Table>), chain_trace = record)]
+/// impl Resolver for Lookup {}
+/// ```
+/// The output of the method tells if a link answered:
+/// - `Option` methods return the first `Some(_)`, or `None` when no link answers.
+/// - `bool` methods return `true` at the first link that returns `true`, or `false` otherwise.
+/// - `Result` methods return the first `Ok(_)`. When every link fails, `chain_error = last` returns the last error (the default),
+///   `chain_error = first` returns the first one, and `chain_error = path` returns the value of a function that receives the `Vec` of errors.
+/// 
+/// Methods with other outputs, and associated functions, are forwarded to the designated member only. `chain_trace = path` calls
+/// a function `fn(&str, &str)` with the names of the method and the member that answered. The arguments of the method are
+/// cloned for each link, except mutable references, which are reborrowed, and methods that take or return `Self` cannot be chained.
 pub use forwarding::forward_trait as forward_trait;
 
+/// Declares the partial implementation of a trait derived with [`except(..)` or `only(..)`](derive@Forwarding#forward_derive),
+/// which implements the methods that aren't forwarded. The attribute completes the implementation with the forwarded methods
+/// and the associated items generated by the derive, so it has to be declared after the struct, and it only declares the excluded methods:
+/// ```text
+/// #[derive(Forwarding)]
+/// struct Robot
+/// {
+///     #[forward_derive(Walker(only(name)))]
+///     legs:Legs
+/// }
+/// 
+/// #[forward_partial]
+/// impl Walker for Robot
+/// {
+///     fn walk(&mut self, steps:u32) -> u32 { self.legs.walk(steps * 2) }
+///     fn position(&self) -> u32 { self.legs.position() * 10 }
+/// }
+/// ```
+/// The trait isn't implemented until the partial implementation is declared, as the derive leaves the implementation to it.
+pub use forwarding::forward_partial as forward_partial;
+
+
+mod arc_slot;
+pub use arc_slot::ArcSlot;
 
 #[doc(hidden)]
 pub use forwarding::ForwardingTraitImplProcess as ForwardingTraitImplProcess;
@@ -0,0 +1,99 @@
+#[cfg(test)]
+mod tests {
+    use std::future::Future;
+    use std::pin::pin;
+    use std::task::{Context, Poll, Waker};
+
+    fn block_on<F:Future>(fut:F) -> F::Output {
+        let mut fut = pin!(fut);
+        let mut cx = Context::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(output) = fut.as_mut().poll(&mut cx) {
+                return output;
+            }
+        }
+    }
+
+    fn assert_send<T:Send>(value:T) -> T {
+        value
+    }
+
+    #[test]
+    fn async_methods_test() {
+
+        #[hereditary::trait_info]
+        trait Service
+        {
+            async fn fetch(&self, id:u64) -> String;
+            async fn store(&mut self, value:String) -> usize;
+            async fn reset(self) -> Self;
+            fn ping(&self, id:u64) -> impl Future<Output = u64> + Send;
+            fn name(&self) -> String;
+        }
+
+        struct Storage
+        {
+            values:Vec<String>
+        }
+
+        impl Service for Storage
+        {
+            async fn fetch(&self, id:u64) -> String {
+                self.values.get(id as usize).cloned().unwrap_or_default()
+            }
+
+            async fn store(&mut self, value:String) -> usize {
+                self.values.push(value);
+                self.values.len()
+            }
+
+            async fn reset(self) -> Self {
+                Storage{values:Vec::new()}
+            }
+
+            fn ping(&self, id:u64) -> impl Future<Output = u64> + Send {
+                std::future::ready(id + 1)
+            }
+
+            fn name(&self) -> String {
+                "storage".to_string()
+            }
+        }
+
+        #[derive(hereditary::Forwarding)]
+        struct Cache
+        {
+            #[forward_derive(Service)]
+            storage:Storage,
+            hits:u32
+        }
+
+        struct Gateway
+        {
+            backend:Storage
+        }
+
+        #[hereditary::forward_trait(backend)]
+        impl Service for Gateway
+        {
+            fn name(&self) -> String {
+                "gateway".to_string()
+            }
+        }
+
+        let mut cache = Cache{storage:Storage{values:Vec::new()}, hits:0};
+        assert_eq!(block_on(assert_send(cache.store("first".to_string()))), 1);
+        assert_eq!(block_on(assert_send(cache.fetch(0))), "first");
+        assert_eq!(block_on(assert_send(cache.ping(4))), 5);
+        assert_eq!(cache.name(), "storage");
+
+        let cache = block_on(assert_send(cache.reset()));
+        assert_eq!((cache.storage.values.len(), cache.hits), (0, 0));
+
+        let mut gateway = Gateway{backend:Storage{values:Vec::new()}};
+        block_on(assert_send(gateway.store("second".to_string())));
+        assert_eq!(block_on(assert_send(gateway.fetch(0))), "second");
+        assert_eq!(block_on(assert_send(gateway.ping(1))), 2);
+        assert_eq!(gateway.name(), "gateway");
+    }
+}
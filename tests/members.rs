@@ -0,0 +1,1039 @@
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn tuple_members_test() {
+
+        #[hereditary::trait_info]
+        trait Measure
+        {
+            fn value(&self) -> f64;
+            fn scale(&mut self, factor:f64);
+            fn larger(&self, other:&Self) -> bool;
+        }
+
+        #[hereditary::trait_info]
+        trait Logger
+        {
+            fn log(&mut self, message:&str);
+            fn entries(&self) -> usize;
+        }
+
+        struct Length
+        {
+            meters:f64
+        }
+
+        impl Measure for Length
+        {
+            fn value(&self) -> f64 {
+                self.meters
+            }
+
+            fn scale(&mut self, factor:f64) {
+                self.meters *= factor;
+            }
+
+            fn larger(&self, other:&Self) -> bool {
+                self.meters > other.meters
+            }
+        }
+
+        struct Journal
+        {
+            lines:Vec<String>
+        }
+
+        impl Logger for Journal
+        {
+            fn log(&mut self, message:&str) {
+                self.lines.push(message.to_string());
+            }
+
+            fn entries(&self) -> usize {
+                self.lines.len()
+            }
+        }
+
+        // newtype
+        #[derive(hereditary::Forwarding)]
+        struct Meters(#[forward_derive(Measure)] Length);
+
+        // generic tuple struct with several forwarded fields
+        #[derive(hereditary::Forwarding)]
+        struct Logged<T>(#[forward_derive(Measure)] T, #[forward_derive(Logger)] Journal) where T:Measure;
+
+        struct Span(u8, Length);
+
+        #[hereditary::forward_trait(1)]
+        impl Measure for Span {}
+
+        let mut distance = Meters(Length{meters:2.0});
+        distance.scale(3.0);
+        assert_eq!(distance.value(), 6.0);
+        assert!(distance.larger(&Meters(Length{meters:1.0})));
+
+        let mut logged = Logged(Length{meters:1.5}, Journal{lines:Vec::new()});
+        logged.log("created");
+        logged.scale(2.0);
+        assert_eq!((logged.value(), logged.entries()), (3.0, 1));
+
+        let span = Span(0, Length{meters:4.0});
+        assert_eq!((span.value(), span.0), (4.0, 0));
+    }
+
+    #[test]
+    fn pointer_members_test() {
+        use std::cell::RefCell;
+
+        #[hereditary::trait_info]
+        trait Counter
+        {
+            fn count(&self) -> u32;
+            fn bump(&mut self, step:u32);
+        }
+
+        struct Clicks
+        {
+            total:u32
+        }
+
+        impl Counter for Clicks
+        {
+            fn count(&self) -> u32 {
+                self.total
+            }
+
+            fn bump(&mut self, step:u32) {
+                self.total += step;
+            }
+        }
+
+        struct Boxed
+        {
+            inner:Box<Clicks>
+        }
+
+        #[hereditary::forward_trait(inner)]
+        impl Counter for Boxed {}
+
+        struct Dynamic
+        {
+            inner:Box<dyn Counter>
+        }
+
+        #[hereditary::forward_trait(inner)]
+        impl Counter for Dynamic {}
+
+        struct Annotated
+        {
+            inner:Box<dyn Counter>
+        }
+
+        #[hereditary::forward_trait(inner: Box<dyn Counter>)]
+        impl Counter for Annotated {}
+
+        struct Borrowed<'a>
+        {
+            inner:&'a mut Clicks
+        }
+
+        #[hereditary::forward_trait(inner)]
+        impl Counter for Borrowed<'_> {}
+
+        struct Shared
+        {
+            inner:RefCell<Box<dyn Counter>>
+        }
+
+        #[hereditary::forward_trait(inner: RefCell<Box<dyn Counter>>, cell = refcell)]
+        impl Counter for Shared {}
+
+        let mut boxed = Boxed{inner:Box::new(Clicks{total:1})};
+        boxed.bump(2);
+        assert_eq!(boxed.count(), 3);
+
+        let mut dynamic = Dynamic{inner:Box::new(Clicks{total:0})};
+        dynamic.bump(4);
+        assert_eq!(dynamic.count(), 4);
+
+        let mut annotated = Annotated{inner:Box::new(Clicks{total:5})};
+        annotated.bump(1);
+        assert_eq!(annotated.count(), 6);
+
+        let mut clicks = Clicks{total:7};
+        let mut borrowed = Borrowed{inner:&mut clicks};
+        borrowed.bump(3);
+        assert_eq!(borrowed.count(), 10);
+        assert_eq!(clicks.total, 10);
+
+        let mut shared = Shared{inner:RefCell::new(Box::new(Clicks{total:2}))};
+        shared.bump(2);
+        assert_eq!(shared.count(), 4);
+    }
+
+    #[test]
+    fn enum_dispatch_test() {
+
+        #[hereditary::trait_info]
+        trait Shape
+        {
+            fn area(&self) -> f64;
+            fn grow(&mut self, factor:f64);
+        }
+
+        #[hereditary::trait_info]
+        trait Doubling
+        {
+            fn doubled(self) -> Self;
+        }
+
+        #[hereditary::trait_info]
+        trait Named
+        {
+            fn name(&self) -> String;
+        }
+
+        struct Circle
+        {
+            radius:f64
+        }
+
+        impl Shape for Circle
+        {
+            fn area(&self) -> f64 {
+                3.0 * self.radius * self.radius
+            }
+
+            fn grow(&mut self, factor:f64) {
+                self.radius *= factor;
+            }
+        }
+
+        impl Doubling for Circle
+        {
+            fn doubled(self) -> Self {
+                Circle{radius:self.radius * 2.0}
+            }
+        }
+
+        impl Named for Circle
+        {
+            fn name(&self) -> String {
+                "circle".to_string()
+            }
+        }
+
+        struct Square
+        {
+            side:f64
+        }
+
+        impl Shape for Square
+        {
+            fn area(&self) -> f64 {
+                self.side * self.side
+            }
+
+            fn grow(&mut self, factor:f64) {
+                self.side *= factor;
+            }
+        }
+
+        impl Doubling for Square
+        {
+            fn doubled(self) -> Self {
+                Square{side:self.side * 2.0}
+            }
+        }
+
+        // single field variants forward by default, and the rest designate a field or follow the fallback
+        #[derive(hereditary::Forwarding)]
+        #[forward_derive(Shape, Named, fallback = default)]
+        #[forward_derive(Doubling, fallback = panic)]
+        enum Figure
+        {
+            Round(Circle),
+            #[forward_derive(Named, fallback = default)]
+            Boxed{square:Box<Square>},
+            Tiled(#[forward_derive(Shape)] Square, #[forward_derive(Named)] Circle),
+            Empty
+        }
+
+        let mut round = Figure::Round(Circle{radius:1.0});
+        round.grow(2.0);
+        assert_eq!((round.area(), round.name()), (12.0, "circle".to_string()));
+        assert!(matches!(round.doubled(), Figure::Round(Circle{radius}) if radius == 4.0));
+
+        let boxed = Figure::Boxed{square:Box::new(Square{side:3.0})};
+        assert_eq!((boxed.area(), boxed.name()), (9.0, String::new()));
+        assert!(matches!(boxed.doubled(), Figure::Boxed{square} if square.side == 6.0));
+
+        let mut tiled = Figure::Tiled(Square{side:2.0}, Circle{radius:0.0});
+        tiled.grow(0.5);
+        assert_eq!((tiled.area(), tiled.name()), (1.0, "circle".to_string()));
+        assert!(std::panic::catch_unwind(|| tiled.doubled()).is_err());
+
+        assert_eq!((Figure::Empty.area(), Figure::Empty.name()), (0.0, String::new()));
+    }
+
+    #[test]
+    fn generic_field_bounds_test() {
+
+        #[hereditary::trait_info]
+        trait Writer
+        {
+            fn write(&mut self, text:&str) -> usize;
+            fn written(&self) -> usize;
+        }
+
+        struct Sink
+        {
+            total:usize
+        }
+
+        impl Writer for Sink
+        {
+            fn write(&mut self, text:&str) -> usize {
+                self.total += text.len();
+                text.len()
+            }
+
+            fn written(&self) -> usize {
+                self.total
+            }
+        }
+
+        // bounds for the generic fields are declared by the derived implementation
+        #[derive(hereditary::Forwarding)]
+        struct Logged<T>
+        {
+            #[forward_derive(Writer)]
+            inner:T,
+            lines:usize
+        }
+
+        #[derive(hereditary::Forwarding)]
+        struct Decorated<'a, W:?Sized>
+        {
+            #[forward_derive(Writer)]
+            inner:&'a mut W
+        }
+
+        #[derive(hereditary::Forwarding)]
+        #[forward_derive(Writer)]
+        enum Either<L, R>
+        {
+            Left(L),
+            Right(Box<R>)
+        }
+
+        let mut logged = Logged{inner:Sink{total:0}, lines:0};
+        logged.lines += 1;
+        assert_eq!((logged.write("abc"), logged.lines), (3, 1));
+
+        let mut decorated = Decorated{inner:&mut logged};
+        decorated.write("de");
+        assert_eq!(logged.written(), 5);
+
+        let mut either:Either<Sink, Logged<Sink>> = Either::Right(Box::new(Logged{inner:Sink{total:1}, lines:0}));
+        either.write("f");
+        assert_eq!(either.written(), 2);
+        assert_eq!(Either::<Sink, Sink>::Left(Sink{total:4}).written(), 4);
+    }
+
+    #[test]
+    fn accessor_members_test() {
+
+        #[hereditary::trait_info]
+        trait Wing
+        {
+            fn span(&self) -> f64;
+            fn flap(&mut self, strength:f64) -> f64;
+        }
+
+        struct Feathers
+        {
+            length:f64
+        }
+
+        impl Wing for Feathers
+        {
+            fn span(&self) -> f64 {
+                self.length * 2.0
+            }
+
+            fn flap(&mut self, strength:f64) -> f64 {
+                self.length += strength;
+                self.length
+            }
+        }
+
+        struct Parts
+        {
+            wings:(Feathers, Feathers)
+        }
+
+        // nested component
+        struct Eagle
+        {
+            parts:Parts
+        }
+
+        #[hereditary::forward_trait(via = self.parts.wings.1)]
+        impl Wing for Eagle {}
+
+        // components behind getters
+        struct Phoenix
+        {
+            wings:Vec<Feathers>
+        }
+
+        impl Phoenix
+        {
+            fn left(&self) -> &Feathers {
+                &self.wings[0]
+            }
+
+            fn left_mut(&mut self) -> &mut Feathers {
+                &mut self.wings[0]
+            }
+        }
+
+        #[hereditary::forward_trait(via_ref = left(), via_mut = left_mut())]
+        impl Wing for Phoenix {}
+
+        // components behind a lock
+        struct Harpy
+        {
+            wing:std::cell::RefCell<Feathers>
+        }
+
+        #[hereditary::forward_trait(via = self.wing.borrow_mut())]
+        impl Wing for Harpy {}
+
+        let mut eagle = Eagle{parts:Parts{wings:(Feathers{length:1.0}, Feathers{length:2.0})}};
+        assert_eq!((eagle.flap(1.0), eagle.span()), (3.0, 6.0));
+        assert_eq!(eagle.parts.wings.0.length, 1.0);
+
+        let mut phoenix = Phoenix{wings:vec![Feathers{length:1.5}]};
+        assert_eq!((phoenix.flap(0.5), phoenix.span()), (2.0, 4.0));
+
+        let mut harpy = Harpy{wing:std::cell::RefCell::new(Feathers{length:0.5})};
+        assert_eq!((harpy.flap(0.5), harpy.span()), (1.0, 2.0));
+    }
+
+    #[test]
+    fn cell_members_test() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+        use std::sync::{Arc, Mutex, RwLock};
+
+        #[hereditary::trait_info]
+        trait Counter
+        {
+            type Step;
+            fn count(&self) -> u32;
+            fn increment(&mut self, step:u32) -> u32;
+            fn into_count(self) -> u32;
+        }
+
+        #[derive(Default)]
+        struct Tally
+        {
+            total:u32
+        }
+
+        impl Counter for Tally
+        {
+            type Step = u32;
+
+            fn count(&self) -> u32 {
+                self.total
+            }
+
+            fn increment(&mut self, step:u32) -> u32 {
+                self.total += step;
+                self.total
+            }
+
+            fn into_count(self) -> u32 {
+                self.total
+            }
+        }
+
+        // shared components are mutated through the guards of their cells
+        #[derive(hereditary::Forwarding)]
+        struct Shared
+        {
+            #[forward_derive(Counter, cell = refcell)]
+            tally:RefCell<Tally>
+        }
+
+        // shared cells cannot be moved out of their pointer
+        #[derive(hereditary::Forwarding)]
+        struct Synced<T:Default>
+        {
+            #[forward_derive(Counter(except(into_count = take_count)), cell = mutex)]
+            tally:Arc<Mutex<T>>
+        }
+
+        impl<T:Default + Counter> Synced<T>
+        {
+            fn take_count(self) -> u32 {
+                std::mem::take(&mut *self.tally.lock().unwrap()).into_count()
+            }
+        }
+
+        struct Guarded
+        {
+            tally:RwLock<Tally>
+        }
+
+        #[hereditary::forward_trait(tally, cell = rwlock)]
+        impl Counter for Guarded
+        {
+            type Step = u32;
+        }
+
+        // errors are returned through the `on_error` function
+        #[hereditary::trait_info]
+        trait Tracker
+        {
+            fn current(&self) -> Result<u32, String>;
+        }
+
+        impl Tracker for Tally
+        {
+            fn current(&self) -> Result<u32, String> {
+                Ok(self.total)
+            }
+        }
+
+        fn busy<E:std::fmt::Display>(error:E) -> Result<u32, String> {
+            Err(error.to_string())
+        }
+
+        struct Watched
+        {
+            tally:Rc<RefCell<Tally>>
+        }
+
+        #[hereditary::forward_trait(tally: Rc<RefCell<Tally>>, cell = refcell, on_error = busy)]
+        impl Tracker for Watched {}
+
+        let shared = Shared{tally:RefCell::new(Tally::default())};
+        let mut alias = Shared{tally:RefCell::new(Tally{total:2})};
+        assert_eq!((alias.increment(3), shared.count()), (5, 0));
+        assert_eq!(Counter::into_count(alias), 5);
+
+        let tally = Arc::new(Mutex::new(Tally{total:1}));
+        let mut synced = Synced{tally:tally.clone()};
+        let step:<Synced<Tally> as Counter>::Step = 4;
+        assert_eq!(synced.increment(step), 5);
+        assert_eq!(tally.lock().unwrap().total, 5);
+        assert_eq!((synced.count(), synced.into_count(), tally.lock().unwrap().total), (5, 5, 0));
+
+        let mut guarded = Guarded{tally:RwLock::new(Tally{total:7})};
+        assert_eq!((guarded.increment(1), guarded.count(), guarded.into_count()), (8, 8, 8));
+
+        let watched = Watched{tally:Rc::new(RefCell::new(Tally{total:3}))};
+        assert_eq!(watched.current(), Ok(3));
+        let _borrowed = watched.tally.borrow_mut();
+        assert!(watched.current().is_err());
+    }
+
+    #[test]
+    fn optional_members_test() {
+        use std::cell::RefCell;
+        use std::rc::{Rc, Weak};
+
+        #[hereditary::trait_info]
+        trait Gauge
+        {
+            fn level(&self) -> u32;
+            fn raise(&mut self, by:u32) -> u32;
+            fn label(&self) -> String;
+        }
+
+        struct Meter
+        {
+            value:u32
+        }
+
+        impl Gauge for Meter
+        {
+            fn level(&self) -> u32 {
+                self.value
+            }
+
+            fn raise(&mut self, by:u32) -> u32 {
+                self.value += by;
+                self.value
+            }
+
+            fn label(&self) -> String {
+                format!("meter {}", self.value)
+            }
+        }
+
+        // fallback functions take the receiver and the arguments of the method
+        fn unplugged(socket:&Socket) -> String {
+            format!("unplugged {}", socket.outlet)
+        }
+
+        // missing components return the default of the output, unless the method has its own fallback
+        #[derive(hereditary::Forwarding)]
+        struct Socket
+        {
+            #[forward_derive(Gauge, optional = option, fallback = default, method_fallback(label = unplugged))]
+            gauge:Option<Box<dyn Gauge>>,
+            outlet:u8
+        }
+
+        // back-references are upgraded on every call, and panic once their component is dropped
+        struct Panel
+        {
+            gauge:Weak<RefCell<Meter>>
+        }
+
+        #[hereditary::forward_trait(gauge: Weak<RefCell<Meter>>, optional = weak, cell = refcell)]
+        impl Gauge for Panel {}
+
+        fn raise_reserve(dial:&mut Dial, by:u32) -> u32 {
+            dial.reserve += by;
+            dial.reserve
+        }
+
+        // dropped back-references follow the fallbacks too
+        #[derive(hereditary::Forwarding)]
+        struct Dial
+        {
+            #[forward_derive(Gauge, optional = weak, cell = refcell, fallback = default, method_fallback(raise = raise_reserve))]
+            gauge:Weak<RefCell<Meter>>,
+            reserve:u32
+        }
+
+        let mut socket = Socket{gauge:None, outlet:2};
+        assert_eq!((socket.raise(2), socket.level(), socket.label()), (0, 0, String::from("unplugged 2")));
+        socket.gauge = Some(Box::new(Meter{value:1}));
+        assert_eq!((socket.raise(2), socket.level(), socket.label()), (3, 3, String::from("meter 3")));
+
+        let meter = Rc::new(RefCell::new(Meter{value:4}));
+        let mut panel = Panel{gauge:Rc::downgrade(&meter)};
+        assert_eq!((panel.raise(1), panel.label()), (5, String::from("meter 5")));
+        assert_eq!(meter.borrow().value, 5);
+
+        let dial_meter = Rc::new(RefCell::new(Meter{value:1}));
+        let mut dial = Dial{gauge:Rc::downgrade(&dial_meter), reserve:10};
+        assert_eq!((dial.raise(1), dial.level(), dial.reserve), (2, 2, 10));
+
+        drop(meter);
+        drop(dial_meter);
+        let dropped = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| panel.level()));
+        let message = dropped.unwrap_err().downcast::<String>().unwrap();
+        assert!(message.contains("the component of the field `gauge` was dropped"));
+        assert_eq!((dial.raise(5), dial.level(), dial.label()), (15, 0, String::new()));
+    }
+
+    #[test]
+    fn lazy_members_test() {
+        use std::cell::{Cell, LazyCell, OnceCell};
+
+        #[hereditary::trait_info]
+        trait Lookup
+        {
+            type Key;
+            fn find(&self, key:u32) -> Option<usize>;
+            fn insert(&mut self, key:u32) -> usize;
+            fn into_keys(self) -> Vec<u32>;
+        }
+
+        struct Index
+        {
+            keys:Vec<u32>
+        }
+
+        impl Lookup for Index
+        {
+            type Key = u32;
+
+            fn find(&self, key:u32) -> Option<usize> {
+                self.keys.iter().position(|k| *k == key)
+            }
+
+            fn insert(&mut self, key:u32) -> usize {
+                self.keys.push(key);
+                self.keys.len() - 1
+            }
+
+            fn into_keys(self) -> Vec<u32> {
+                self.keys
+            }
+        }
+
+        thread_local! {
+            static BUILDS:Cell<u32> = const { Cell::new(0) };
+        }
+
+        fn build_index() -> Index {
+            BUILDS.with(|builds| builds.set(builds.get() + 1));
+            Index{keys:vec![10, 20]}
+        }
+
+        // the index is built by the first forwarded call
+        #[derive(hereditary::Forwarding)]
+        struct Catalog
+        {
+            #[forward_derive(Lookup, lazy = build_index)]
+            index:OnceCell<Index>
+        }
+
+        #[hereditary::trait_info]
+        trait Search
+        {
+            fn lookup(&self, key:u32) -> Option<usize>;
+            fn add(&mut self, key:u32) -> usize;
+        }
+
+        impl Search for Index
+        {
+            fn lookup(&self, key:u32) -> Option<usize> {
+                self.find(key)
+            }
+
+            fn add(&mut self, key:u32) -> usize {
+                self.insert(key)
+            }
+        }
+
+        // lazy cells hold their own initializer, and they are forced on first use
+        struct Archive
+        {
+            index:LazyCell<Index>
+        }
+
+        #[hereditary::forward_trait(index: LazyCell<Index>, lazy = force)]
+        impl Search for Archive {}
+
+        let catalog = Catalog{index:OnceCell::new()};
+        assert_eq!(BUILDS.with(Cell::get), 0);
+        let key:<Catalog as Lookup>::Key = 20;
+        assert_eq!((catalog.find(key), catalog.find(30)), (Some(1), None));
+        assert_eq!(BUILDS.with(Cell::get), 1);
+
+        let mut fresh = Catalog{index:OnceCell::new()};
+        assert_eq!((fresh.insert(30), fresh.find(30)), (2, Some(2)));
+        assert_eq!(Catalog{index:OnceCell::new()}.into_keys(), vec![10, 20]);
+        assert_eq!((catalog.into_keys(), BUILDS.with(Cell::get)), (vec![10, 20], 3));
+
+        let mut archive = Archive{index:LazyCell::new(build_index)};
+        assert_eq!((archive.add(40), archive.lookup(40), archive.lookup(10)), (2, Some(2), Some(0)));
+        assert_eq!(BUILDS.with(Cell::get), 4);
+    }
+
+    #[test]
+    fn broadcast_members_test() {
+        use std::collections::HashMap;
+
+        #[hereditary::trait_info]
+        trait Shape
+        {
+            fn area(&self) -> f64;
+            fn scale(&mut self, factor:f64);
+            fn names(&self) -> Vec<String>;
+            fn widest(&self) -> Option<f64>;
+            fn leaves(&self) -> usize;
+        }
+
+        struct Square
+        {
+            side:f64
+        }
+
+        impl Shape for Square
+        {
+            fn area(&self) -> f64 {
+                self.side * self.side
+            }
+
+            fn scale(&mut self, factor:f64) {
+                self.side *= factor;
+            }
+
+            fn names(&self) -> Vec<String> {
+                vec![format!("square {}", self.side)]
+            }
+
+            fn widest(&self) -> Option<f64> {
+                Some(self.side)
+            }
+
+            fn leaves(&self) -> usize {
+                1
+            }
+        }
+
+        fn add_leaves(total:usize, leaves:usize) -> usize {
+            total + leaves
+        }
+
+        // groups are shapes too, so they can be nested
+        #[derive(hereditary::Forwarding)]
+        struct Group
+        {
+            #[forward_derive(Shape, broadcast, aggregate(area = sum, names = collect, widest = max, leaves = add_leaves))]
+            children:Vec<Box<dyn Shape>>
+        }
+
+        #[hereditary::trait_info]
+        trait Sensor
+        {
+            fn read(&self) -> i32;
+            fn peak(&self) -> Option<i32>;
+            fn online(&self) -> bool;
+            fn calibrate(&mut self, offset:i32, log:&mut Vec<i32>);
+        }
+
+        struct Probe
+        {
+            value:i32
+        }
+
+        impl Sensor for Probe
+        {
+            fn read(&self) -> i32 {
+                self.value
+            }
+
+            fn peak(&self) -> Option<i32> {
+                if self.value >= 0 {Some(self.value)} else {None}
+            }
+
+            fn online(&self) -> bool {
+                self.value >= 0
+            }
+
+            fn calibrate(&mut self, offset:i32, log:&mut Vec<i32>) {
+                self.value += offset;
+                log.push(self.value);
+            }
+        }
+
+        struct Array
+        {
+            probes:[Probe; 3]
+        }
+
+        #[hereditary::forward_trait(probes: [Probe; 3], broadcast, aggregate(read = sum, peak = min, online = all))]
+        impl Sensor for Array {}
+
+        // maps broadcast to their values
+        #[derive(hereditary::Forwarding)]
+        struct Registry
+        {
+            #[forward_derive(Sensor, broadcast, aggregate(read = sum, peak = max, online = any))]
+            probes:HashMap<String, Probe>
+        }
+
+        let mut group = Group{children:vec![
+            Box::new(Square{side:1.0}),
+            Box::new(Group{children:vec![Box::new(Square{side:2.0}), Box::new(Square{side:3.0})]})
+        ]};
+        assert_eq!((group.area(), group.widest(), group.leaves()), (14.0, Some(3.0), 3));
+        group.scale(2.0);
+        assert_eq!(group.names(), vec![String::from("square 2"), String::from("square 4"), String::from("square 6")]);
+
+        let mut array = Array{probes:[Probe{value:3}, Probe{value:-1}, Probe{value:5}]};
+        assert_eq!((array.read(), array.peak(), array.online()), (7, Some(3), false));
+        let mut log = Vec::new();
+        array.calibrate(2, &mut log);
+        assert_eq!((array.read(), array.peak(), array.online(), log), (13, Some(1), true, vec![5, 1, 7]));
+
+        let registry = Registry{probes:HashMap::from([(String::from("in"), Probe{value:-4}), (String::from("out"), Probe{value:9})])};
+        assert_eq!((registry.read(), registry.peak(), registry.online()), (5, Some(9), true));
+
+        // the least and greatest values of empty collections are missing
+        let empty = Registry{probes:HashMap::new()};
+        assert_eq!((empty.read(), empty.peak(), empty.online()), (0, None, false));
+        assert_eq!((Group{children:Vec::new()}.area(), Group{children:Vec::new()}.widest()), (0.0, None));
+    }
+
+    #[test]
+    fn chain_members_test() {
+        use std::cell::RefCell;
+
+        #[hereditary::trait_info]
+        trait Resolver
+        {
+            fn resolve(&self, name:&str) -> Option<u32>;
+            fn load(&mut self, name:&str) -> Result<u32, String>;
+            fn knows(&self, name:&str) -> bool;
+            fn label(&self) -> String;
+        }
+
+        struct Table
+        {
+            label:&'static str,
+            entries:Vec<(&'static str, u32)>
+        }
+
+        impl Resolver for Table
+        {
+            fn resolve(&self, name:&str) -> Option<u32> {
+                self.entries.iter().find(|(key, _)| *key == name).map(|(_, value)| *value)
+            }
+
+            fn load(&mut self, name:&str) -> Result<u32, String> {
+                self.resolve(name).ok_or_else(|| format!("{} misses {}", self.label, name))
+            }
+
+            fn knows(&self, name:&str) -> bool {
+                self.resolve(name).is_some()
+            }
+
+            fn label(&self) -> String {
+                String::from(self.label)
+            }
+        }
+
+        thread_local! {
+            static ANSWERS:RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+        }
+
+        fn record(method:&str, link:&str) {
+            ANSWERS.with(|answers| answers.borrow_mut().push(format!("{}:{}", method, link)));
+        }
+
+        // the cache answers first, then the disk and the stubs in order
+        struct Lookup
+        {
+            cache:Table,
+            disk:Box<Table>,
+            stubs:Vec<Table>
+        }
+
+        #[hereditary::forward_trait(chain(cache, disk: Box<Table>, ..stubs: Vec<Table>), chain_trace = record)]
+        impl Resolver for Lookup {}
+
+        fn join_errors(errors:Vec<String>) -> String {
+            errors.join(", ")
+        }
+
+        #[derive(hereditary::Forwarding)]
+        struct Layered
+        {
+            #[forward_derive(Resolver, chain(fallback), chain_error = join_errors)]
+            primary:Table,
+            fallback:Table
+        }
+
+        let table = |label:&'static str, entries:Vec<(&'static str, u32)>| Table{label, entries};
+        let mut lookup = Lookup{
+            cache:table("cache", vec![("a", 1)]),
+            disk:Box::new(table("disk", vec![("a", 2), ("b", 3)])),
+            stubs:vec![table("stub 1", vec![]), table("stub 2", vec![("c", 4)])]
+        };
+        assert_eq!((lookup.resolve("a"), lookup.resolve("b"), lookup.resolve("c"), lookup.resolve("d")), (Some(1), Some(3), Some(4), None));
+        assert_eq!((lookup.load("c"), lookup.load("d")), (Ok(4), Err(String::from("stub 2 misses d"))));
+        assert_eq!((lookup.knows("b"), lookup.knows("d"), lookup.label()), (true, false, String::from("cache")));
+        assert_eq!(ANSWERS.with(|answers| answers.borrow().clone()), vec![
+            "resolve:cache", "resolve:disk", "resolve:stubs", "load:stubs", "knows:disk"
+        ]);
+
+        let mut layered = Layered{primary:table("primary", vec![("a", 1)]), fallback:table("fallback", vec![("b", 2)])};
+        assert_eq!((layered.load("b"), layered.load("c")), (Ok(2), Err(String::from("primary misses c, fallback misses c"))));
+    }
+
+    #[test]
+    fn swappable_members_test() {
+        use std::sync::Arc;
+
+        #[hereditary::trait_info]
+        trait Pricing
+        {
+            fn price(&self, base:u32) -> u32;
+            fn discount(&mut self, percent:u32);
+        }
+
+        struct Flat;
+
+        impl Pricing for Flat
+        {
+            fn price(&self, base:u32) -> u32 {
+                base
+            }
+
+            fn discount(&mut self, _percent:u32) {}
+        }
+
+        struct Reduced
+        {
+            percent:u32
+        }
+
+        impl Pricing for Reduced
+        {
+            fn price(&self, base:u32) -> u32 {
+                base * (100 - self.percent) / 100
+            }
+
+            fn discount(&mut self, percent:u32) {
+                self.percent += percent;
+            }
+        }
+
+        // the pricing strategy can be swapped, as well as replaced by test doubles
+        #[derive(hereditary::Forwarding)]
+        struct Checkout
+        {
+            #[forward_derive(Pricing, swappable)]
+            pricing:Box<dyn Pricing>
+        }
+
+        #[hereditary::trait_info]
+        trait Quote
+        {
+            fn quote(&self, base:u32) -> u32;
+        }
+
+        impl Quote for Flat
+        {
+            fn quote(&self, base:u32) -> u32 {
+                base
+            }
+        }
+
+        impl Quote for Reduced
+        {
+            fn quote(&self, base:u32) -> u32 {
+                self.price(base)
+            }
+        }
+
+        // shared desks swap their strategy from any thread
+        #[derive(hereditary::Forwarding)]
+        struct Desk
+        {
+            #[forward_derive(Quote, swappable)]
+            quote:hereditary::ArcSlot<dyn Quote + Send + Sync>
+        }
+
+        let mut checkout = Checkout{pricing:Box::new(Flat)}.with_pricing(Box::new(Reduced{percent:10}));
+        checkout.discount(10);
+        assert_eq!(checkout.price(200), 160);
+        let previous = checkout.replace_pricing(Box::new(Flat));
+        assert_eq!((previous.price(200), checkout.price(200)), (160, 200));
+        checkout.set_pricing(previous);
+        assert_eq!(checkout.price(100), 80);
+
+        let desk = Arc::new(Desk{quote:hereditary::ArcSlot::new(Arc::new(Flat))});
+        assert_eq!(desk.quote(50), 50);
+        let shared_desk = desk.clone();
+        std::thread::spawn(move || shared_desk.set_quote(Arc::new(Reduced{percent:50}))).join().unwrap();
+        assert_eq!(desk.quote(50), 25);
+        assert_eq!(desk.replace_quote(Arc::new(Flat)).quote(10), 5);
+        let desk = Arc::into_inner(desk).unwrap().with_quote(Arc::new(Reduced{percent:20}));
+        assert_eq!(desk.quote(10), 8);
+    }
+}
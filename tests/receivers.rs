@@ -0,0 +1,276 @@
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn by_value_receiver_test() {
+
+        #[hereditary::trait_info]
+        trait Consumable
+        {
+            fn name(&self) -> String;
+            fn into_name(self) -> String;
+            fn consume(self, bites:u32) -> u32;
+        }
+
+        struct Apple
+        {
+            label:String,
+            bites:u32
+        }
+
+        impl Consumable for Apple
+        {
+            fn name(&self) -> String {
+                self.label.clone()
+            }
+
+            fn into_name(self) -> String {
+                self.label
+            }
+
+            fn consume(mut self, bites:u32) -> u32 {
+                self.bites += bites;
+                self.bites
+            }
+        }
+
+        // Full delegation moves the apple out of the basket
+        #[derive(hereditary::Forwarding)]
+        struct Basket
+        {
+            #[forward_derive(Consumable)]
+            apple:Apple,
+            #[allow(dead_code)]
+            weight:f64
+        }
+
+        // Partial delegation combined with a custom method
+        struct Lunchbox
+        {
+            fruit:Apple,
+            owner:String
+        }
+
+        #[hereditary::forward_trait(fruit)]
+        impl Consumable for Lunchbox
+        {
+            fn name(&self) -> String {
+                format!("{}'s {}", self.owner, self.fruit.name())
+            }
+        }
+
+        let basket = Basket{apple:Apple{label:"Fuji".into(), bites:0}, weight:1.5};
+        assert_eq!(basket.name(), "Fuji");
+        assert_eq!(basket.into_name(), "Fuji");
+
+        let basket = Basket{apple:Apple{label:"Gala".into(), bites:1}, weight:1.0};
+        assert_eq!(basket.consume(2), 3);
+
+        let lunch = Lunchbox{fruit:Apple{label:"Pink Lady".into(), bites:0}, owner:"Ana".into()};
+        assert_eq!(lunch.name(), "Ana's Pink Lady");
+        assert_eq!(lunch.into_name(), "Pink Lady");
+    }
+
+    #[test]
+    fn smart_pointer_receiver_test() {
+        use std::rc::Rc;
+        use std::sync::Arc;
+
+        #[hereditary::trait_info]
+        trait Unboxing
+        {
+            fn label(&self) -> String;
+            fn into_label(self: Box<Self>) -> String;
+        }
+
+        #[hereditary::trait_info]
+        trait Sharing
+        {
+            fn share_count(self: Rc<Self>) -> usize;
+        }
+
+        #[hereditary::trait_info]
+        trait Syncing
+        {
+            fn sync_label(self: Arc<Self>) -> String;
+        }
+
+        #[derive(Clone)]
+        struct Worker
+        {
+            name:String
+        }
+
+        impl Unboxing for Worker
+        {
+            fn label(&self) -> String {
+                self.name.clone()
+            }
+
+            fn into_label(self: Box<Self>) -> String {
+                self.name
+            }
+        }
+
+        impl Sharing for Worker
+        {
+            fn share_count(self: Rc<Self>) -> usize {
+                Rc::strong_count(&self)
+            }
+        }
+
+        impl Syncing for Worker
+        {
+            fn sync_label(self: Arc<Self>) -> String {
+                format!("{}:{}", self.name, Arc::strong_count(&self))
+            }
+        }
+
+        // The Rc receiver is forwarded to a member declared as Rc
+        #[derive(hereditary::Forwarding)]
+        struct Crew
+        {
+            #[forward_derive(Unboxing)]
+            leader:Worker,
+            #[forward_derive(Sharing)]
+            shared:Rc<Worker>
+        }
+
+        // The Arc receiver requires a projection, because the member isn't an Arc
+        fn project_leader(crew:Arc<Crew>) -> Arc<Worker> {
+            Arc::new(crew.leader.clone())
+        }
+
+        #[hereditary::forward_trait(leader, arc_project = project_leader)]
+        impl Syncing for Crew {}
+
+        let worker = Rc::new(Worker{name:"Bob".into()});
+        let crew = Crew{leader:Worker{name:"Alice".into()}, shared:Rc::clone(&worker)};
+        assert_eq!(crew.label(), "Alice");
+
+        let crew = Rc::new(crew);
+        // worker, crew.shared and the forwarded clone
+        assert_eq!(Rc::clone(&crew).share_count(), 3);
+
+        let crew = Rc::into_inner(crew).unwrap();
+        #[allow(clippy::arc_with_non_send_sync)]
+        let crew = Arc::new(crew);
+        assert_eq!(Arc::clone(&crew).sync_label(), "Alice:1");
+
+        let crew = Box::new(Arc::into_inner(crew).unwrap());
+        assert_eq!(crew.into_label(), "Alice");
+    }
+
+    #[test]
+    fn pinned_receiver_test() {
+        use std::pin::Pin;
+        use std::task::{Context, Poll, Waker};
+
+        // Stream-like trait polled through pinned receivers
+        #[hereditary::trait_info]
+        trait Ticker
+        {
+            fn poll_tick(self: Pin<&mut Self>, cx:&mut Context<'_>) -> Poll<Option<u32>>;
+            fn pending_ticks(self: Pin<&Self>) -> u32;
+        }
+
+        struct Countdown
+        {
+            remaining:u32
+        }
+
+        impl Ticker for Countdown
+        {
+            fn poll_tick(mut self: Pin<&mut Self>, _cx:&mut Context<'_>) -> Poll<Option<u32>> {
+                if self.remaining == 0 {
+                    Poll::Ready(None)
+                }
+                else {
+                    self.remaining -= 1;
+                    Poll::Ready(Some(self.remaining))
+                }
+            }
+
+            fn pending_ticks(self: Pin<&Self>) -> u32 {
+                self.remaining
+            }
+        }
+
+        #[derive(hereditary::Forwarding)]
+        struct Timer
+        {
+            #[forward_derive(Ticker)]
+            countdown:Countdown,
+            #[allow(dead_code)]
+            label:String
+        }
+
+        struct Alarm
+        {
+            countdown:Countdown,
+            snoozed:bool
+        }
+
+        #[hereditary::forward_trait(countdown)]
+        impl Ticker for Alarm
+        {
+            fn pending_ticks(self: Pin<&Self>) -> u32 {
+                if self.snoozed {0} else {Pin::new(&self.countdown).pending_ticks()}
+            }
+        }
+
+        let mut cx = Context::from_waker(Waker::noop());
+
+        let mut timer = Timer{countdown:Countdown{remaining:2}, label:"tea".into()};
+        assert_eq!(Pin::new(&timer).pending_ticks(), 2);
+        assert_eq!(Pin::new(&mut timer).poll_tick(&mut cx), Poll::Ready(Some(1)));
+        assert_eq!(Pin::new(&mut timer).poll_tick(&mut cx), Poll::Ready(Some(0)));
+        assert_eq!(Pin::new(&mut timer).poll_tick(&mut cx), Poll::Ready(None));
+
+        let mut alarm = Box::pin(Alarm{countdown:Countdown{remaining:1}, snoozed:true});
+        assert_eq!(alarm.as_ref().pending_ticks(), 0);
+        assert_eq!(alarm.as_mut().poll_tick(&mut cx), Poll::Ready(Some(0)));
+
+        // components that aren't `Unpin` are stored in a pinned box
+        struct Metronome
+        {
+            beats:std::cell::Cell<u32>,
+            _pinned:std::marker::PhantomPinned
+        }
+
+        impl Ticker for Metronome
+        {
+            fn poll_tick(self: Pin<&mut Self>, _cx:&mut Context<'_>) -> Poll<Option<u32>> {
+                self.beats.set(self.beats.get() + 1);
+                Poll::Ready(Some(self.beats.get()))
+            }
+
+            fn pending_ticks(self: Pin<&Self>) -> u32 {
+                u32::MAX - self.beats.get()
+            }
+        }
+
+        #[derive(hereditary::Forwarding)]
+        struct Band
+        {
+            #[forward_derive(Ticker)]
+            metronome:Pin<Box<Metronome>>
+        }
+
+        struct Clock
+        {
+            metronome:Pin<Box<Metronome>>
+        }
+
+        #[hereditary::forward_trait(metronome: Pin<Box<Metronome>>)]
+        impl Ticker for Clock {}
+
+        let mut band = Band{metronome:Box::pin(Metronome{beats:0.into(), _pinned:std::marker::PhantomPinned})};
+        assert_eq!(Pin::new(&mut band).poll_tick(&mut cx), Poll::Ready(Some(1)));
+        assert_eq!(Pin::new(&band).pending_ticks(), u32::MAX - 1);
+
+        let mut clock = Clock{metronome:Box::pin(Metronome{beats:4.into(), _pinned:std::marker::PhantomPinned})};
+        assert_eq!(Pin::new(&mut clock).poll_tick(&mut cx), Poll::Ready(Some(5)));
+        assert_eq!(Pin::new(&clock).pending_ticks(), u32::MAX - 5);
+    }
+}
@@ -0,0 +1,618 @@
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn associated_functions_test() {
+
+        #[hereditary::trait_info]
+        trait Factory
+        {
+            fn kind() -> &'static str;
+            fn create(level:u32) -> Self;
+            fn parse(text:&str) -> Option<Self> where Self: Sized;
+            fn level(&self) -> u32;
+        }
+
+        #[derive(Default)]
+        struct Gear
+        {
+            teeth:u32
+        }
+
+        impl Factory for Gear
+        {
+            fn kind() -> &'static str {
+                "gear"
+            }
+
+            fn create(level:u32) -> Self {
+                Gear{teeth:level*10}
+            }
+
+            fn parse(text:&str) -> Option<Self> {
+                text.parse::<u32>().ok().map(Self::create)
+            }
+
+            fn level(&self) -> u32 {
+                self.teeth/10
+            }
+        }
+
+        // Remaining fields are filled by Default
+        #[derive(Default, hereditary::Forwarding)]
+        struct Machine
+        {
+            #[forward_derive(Factory, construct = default)]
+            gear:Gear,
+            name:String
+        }
+
+        // Remaining fields are filled by a user function
+        struct Robot
+        {
+            part:Gear,
+            serial:u64
+        }
+
+        impl Robot
+        {
+            fn from_gear(part:Gear) -> Self {
+                Self{part, serial:42}
+            }
+        }
+
+        #[hereditary::forward_trait(part: Gear, construct = Robot::from_gear)]
+        impl Factory for Robot
+        {
+            fn kind() -> &'static str {
+                "robot"
+            }
+        }
+
+        assert_eq!(Machine::kind(), "gear");
+        let machine = Machine::create(3);
+        assert_eq!(machine.level(), 3);
+        assert_eq!(machine.gear.teeth, 30);
+        assert_eq!(machine.name, "");
+        assert!(Machine::parse("x").is_none());
+        assert_eq!(Machine::parse("7").map(|m| m.level()), Some(7));
+
+        assert_eq!(Robot::kind(), "robot");
+        let robot = Robot::create(2);
+        assert_eq!((robot.level(), robot.serial), (2, 42));
+        assert_eq!(Robot::parse("5").map(|r| r.part.teeth), Some(50));
+    }
+
+    #[test]
+    fn rewrap_self_outputs_test() {
+
+        #[hereditary::trait_info]
+        trait Shape
+        {
+            fn scaled(&self, factor:u32) -> Self;
+            fn split(&self) -> (Self, Self) where Self: Sized;
+            fn shrink(&mut self, amount:u32) -> Result<Self, String> where Self: Sized;
+            fn copies(&self, count:usize) -> Vec<Self> where Self: Sized;
+            fn grown(self, amount:u32) -> Self;
+            fn halved(self) -> Option<Self> where Self: Sized;
+            fn size(&self) -> u32;
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        struct Square
+        {
+            side:u32
+        }
+
+        impl Shape for Square
+        {
+            fn scaled(&self, factor:u32) -> Self {
+                Square{side:self.side*factor}
+            }
+
+            fn split(&self) -> (Self, Self) {
+                (Square{side:self.side/2}, Square{side:self.side - self.side/2})
+            }
+
+            fn shrink(&mut self, amount:u32) -> Result<Self, String> {
+                if amount > self.side {
+                    return Err("too small".to_string());
+                }
+                self.side -= amount;
+                Ok(self.clone())
+            }
+
+            fn copies(&self, count:usize) -> Vec<Self> {
+                vec![self.clone(); count]
+            }
+
+            fn grown(self, amount:u32) -> Self {
+                Square{side:self.side + amount}
+            }
+
+            fn halved(self) -> Option<Self> {
+                self.side.is_multiple_of(2).then_some(Square{side:self.side/2})
+            }
+
+            fn size(&self) -> u32 {
+                self.side
+            }
+        }
+
+        // Remaining fields are cloned from the receiver
+        #[derive(Clone, hereditary::Forwarding)]
+        struct Tile
+        {
+            #[forward_derive(Shape, rewrap = clone)]
+            square:Square,
+            color:&'static str
+        }
+
+        // Remaining fields are rebuilt by a user function
+        struct Label
+        {
+            square:Square,
+            text:String
+        }
+
+        impl Label
+        {
+            fn rewrap(original:&Label, square:Square) -> Self {
+                Self{text:format!("{}'", original.text), square}
+            }
+        }
+
+        #[hereditary::forward_trait(square, rewrap = Label::rewrap)]
+        impl Shape for Label
+        {
+            fn grown(self, amount:u32) -> Self {
+                Label{square:self.square.grown(amount), text:self.text}
+            }
+
+            fn halved(self) -> Option<Self> {
+                None
+            }
+        }
+
+        let mut tile = Tile{square:Square{side:8}, color:"red"};
+        let big = tile.scaled(3);
+        assert_eq!((big.size(), big.color), (24, "red"));
+
+        let (left, right) = tile.split();
+        assert_eq!((left.size(), right.size(), right.color), (4, 4, "red"));
+
+        assert!(tile.shrink(10).is_err());
+        let shrunk = tile.shrink(2).unwrap();
+        assert_eq!((shrunk.size(), tile.size()), (6, 6));
+
+        let copies = tile.copies(3);
+        assert_eq!(copies.len(), 3);
+        assert!(copies.iter().all(|t| t.size() == 6 && t.color == "red"));
+
+        // by value methods put the component back into the composite
+        let grown = tile.clone().grown(1);
+        assert_eq!((grown.size(), grown.color), (7, "red"));
+        assert_eq!(tile.clone().halved().map(|t| (t.size(), t.color)), Some((3, "red")));
+        assert!(grown.halved().is_none());
+
+        let label = Label{square:Square{side:5}, text:"a".to_string()};
+        let scaled = label.scaled(2);
+        assert_eq!((scaled.size(), scaled.text.as_str()), (10, "a'"));
+        let (first, second) = scaled.split();
+        assert_eq!((first.size(), second.size(), second.text.as_str()), (5, 5, "a''"));
+        assert_eq!(label.grown(1).square, Square{side:6});
+    }
+
+    #[test]
+    fn self_arguments_test() {
+
+        #[hereditary::trait_info]
+        trait Metric
+        {
+            fn distance(&self, other:&Self) -> f64;
+            fn merge(&mut self, other:Self) where Self: Sized;
+            fn absorb(&mut self, other:&mut Self);
+            fn nearest(&self, others:Vec<&Self>) -> Option<usize> where Self: Sized;
+            fn closer(&self, other:Option<&Self>, limit:f64) -> bool;
+            fn total(&self, others:Vec<Self>) -> f64 where Self: Sized;
+            fn between(first:&Self, second:&Self) -> f64 where Self: Sized;
+        }
+
+        struct Point
+        {
+            x:f64
+        }
+
+        impl Metric for Point
+        {
+            fn distance(&self, other:&Self) -> f64 {
+                (self.x - other.x).abs()
+            }
+
+            fn merge(&mut self, other:Self) {
+                self.x += other.x;
+            }
+
+            fn absorb(&mut self, other:&mut Self) {
+                self.x += other.x;
+                other.x = 0.0;
+            }
+
+            fn nearest(&self, others:Vec<&Self>) -> Option<usize> {
+                (0..others.len()).min_by(|a, b| self.distance(others[*a]).total_cmp(&self.distance(others[*b])))
+            }
+
+            fn closer(&self, other:Option<&Self>, limit:f64) -> bool {
+                other.is_some_and(|p| self.distance(p) < limit)
+            }
+
+            fn total(&self, others:Vec<Self>) -> f64 {
+                others.iter().map(|p| self.distance(p)).sum()
+            }
+
+            fn between(first:&Self, second:&Self) -> f64 {
+                first.distance(second)
+            }
+        }
+
+        #[derive(hereditary::Forwarding)]
+        struct City
+        {
+            #[forward_derive(Metric)]
+            location:Point,
+            name:&'static str
+        }
+
+        #[hereditary::trait_info]
+        trait Blend
+        {
+            fn blend(&mut self, other:Self) where Self: Sized;
+            fn contrast(&self, other:&Self) -> f64;
+            fn swap(&mut self, other:&mut Self);
+            fn brightest(&self, others:Vec<Self>) -> f64 where Self: Sized;
+        }
+
+        impl Blend for Point
+        {
+            fn blend(&mut self, other:Self) {
+                self.x = (self.x + other.x) / 2.0;
+            }
+
+            fn contrast(&self, other:&Self) -> f64 {
+                self.x - other.x
+            }
+
+            fn swap(&mut self, other:&mut Self) {
+                std::mem::swap(&mut self.x, &mut other.x);
+            }
+
+            fn brightest(&self, others:Vec<Self>) -> f64 {
+                others.iter().fold(self.x, |brightest, other| brightest.max(other.x))
+            }
+        }
+
+        // boxed members are moved out of the arguments taken by value, and dereferenced by the borrowed ones
+        #[derive(hereditary::Forwarding)]
+        struct Layer
+        {
+            #[forward_derive(Blend)]
+            tone:Box<Point>
+        }
+
+        struct Overlay
+        {
+            tone:Box<Point>
+        }
+
+        #[hereditary::forward_trait(tone: Box<Point>)]
+        impl Blend for Overlay {}
+
+        let mut paris = City{location:Point{x:2.0}, name:"paris"};
+        let mut rome = City{location:Point{x:12.0}, name:"rome"};
+        let oslo = City{location:Point{x:10.0}, name:"oslo"};
+
+        assert_eq!(paris.distance(&rome), 10.0);
+        assert_eq!(City::between(&rome, &oslo), 2.0);
+        assert_eq!(paris.nearest(vec![&rome, &oslo]), Some(1));
+        assert!(rome.closer(Some(&oslo), 3.0));
+        assert!(!rome.closer(None, 3.0));
+        assert_eq!(paris.total(vec![City{location:Point{x:4.0}, name:"a"}, City{location:Point{x:1.0}, name:"b"}]), 3.0);
+
+        paris.absorb(&mut rome);
+        assert_eq!((paris.location.x, rome.location.x), (14.0, 0.0));
+        paris.merge(oslo);
+        assert_eq!((paris.location.x, paris.name, rome.name), (24.0, "paris", "rome"));
+
+        let mut layer = Layer{tone:Box::new(Point{x:2.0})};
+        let mut backdrop = Layer{tone:Box::new(Point{x:8.0})};
+        assert_eq!(layer.contrast(&backdrop), -6.0);
+        layer.swap(&mut backdrop);
+        assert_eq!((layer.tone.x, backdrop.tone.x), (8.0, 2.0));
+        layer.blend(backdrop);
+        assert_eq!(layer.tone.x, 5.0);
+        assert_eq!(layer.brightest(vec![Layer{tone:Box::new(Point{x:7.0})}]), 7.0);
+
+        let mut overlay = Overlay{tone:Box::new(Point{x:1.0})};
+        overlay.blend(Overlay{tone:Box::new(Point{x:3.0})});
+        assert_eq!((overlay.tone.x, overlay.contrast(&Overlay{tone:Box::new(Point{x:0.5})})), (2.0, 1.5));
+    }
+
+    #[test]
+    fn unsafe_traits_test() {
+
+        /// # Safety
+        /// `size` must report the number of readable bytes.
+        #[hereditary::trait_info]
+        unsafe trait RawBuffer
+        {
+            fn size(&self) -> usize;
+            unsafe fn get_unchecked(&self, index:usize) -> u8;
+            unsafe fn set_unchecked(&mut self, index:usize, value:u8);
+        }
+
+        #[hereditary::trait_info]
+        trait Peek
+        {
+            unsafe fn peek(&self) -> u8;
+        }
+
+        struct Bytes
+        {
+            data:Vec<u8>
+        }
+
+        unsafe impl RawBuffer for Bytes
+        {
+            fn size(&self) -> usize {
+                self.data.len()
+            }
+
+            unsafe fn get_unchecked(&self, index:usize) -> u8 {
+                *self.data.get_unchecked(index)
+            }
+
+            unsafe fn set_unchecked(&mut self, index:usize, value:u8) {
+                *self.data.get_unchecked_mut(index) = value;
+            }
+        }
+
+        impl Peek for Bytes
+        {
+            unsafe fn peek(&self) -> u8 {
+                self.get_unchecked(0)
+            }
+        }
+
+        impl Bytes
+        {
+            unsafe fn read_unchecked(&self, index:usize) -> u8 {
+                *self.data.get_unchecked(index)
+            }
+        }
+
+        #[derive(hereditary::Forwarding)]
+        struct Packet
+        {
+            #[forward_derive(unsafe(RawBuffer), Peek)]
+            payload:Bytes,
+            id:u32
+        }
+
+        struct Frame
+        {
+            inner:Bytes
+        }
+
+        #[hereditary::forward_trait(inner)]
+        unsafe impl RawBuffer for Frame {}
+
+        // the mapped arguments, accessors, cells and inherent methods are evaluated outside of the unsafe block
+        struct Window
+        {
+            parts:Vec<Bytes>
+        }
+
+        #[hereditary::forward_trait(via = self.parts.first().unwrap(), map(peek = read_unchecked(1)))]
+        impl Peek for Window {}
+
+        struct Shared
+        {
+            inner:std::cell::RefCell<Bytes>
+        }
+
+        #[hereditary::forward_trait(inner: std::cell::RefCell<Bytes>, cell = refcell)]
+        unsafe impl RawBuffer for Shared {}
+
+        #[derive(hereditary::Forwarding)]
+        struct Guarded
+        {
+            #[forward_derive(unsafe(RawBuffer(except(set_unchecked = store))))]
+            payload:Bytes
+        }
+
+        impl Guarded
+        {
+            fn store(&mut self, index:usize, value:u8) {
+                if let Some(byte) = self.payload.data.get_mut(index) {
+                    *byte = value;
+                }
+            }
+        }
+
+        let mut packet = Packet{payload:Bytes{data:vec![1, 2, 3]}, id:7};
+        assert_eq!(packet.size(), 3);
+        unsafe {
+            packet.set_unchecked(1, 20);
+            assert_eq!((packet.get_unchecked(1), packet.peek(), packet.id), (20, 1, 7));
+        }
+
+        let frame = Frame{inner:Bytes{data:vec![9]}};
+        assert_eq!((frame.size(), unsafe { frame.get_unchecked(0) }), (1, 9));
+
+        let window = Window{parts:vec![Bytes{data:vec![4, 5]}]};
+        assert_eq!(unsafe { window.peek() }, 5);
+
+        let mut shared = Shared{inner:std::cell::RefCell::new(Bytes{data:vec![1, 2]})};
+        unsafe {
+            shared.set_unchecked(0, 6);
+            assert_eq!((shared.size(), shared.get_unchecked(0)), (2, 6));
+        }
+
+        let mut guarded = Guarded{payload:Bytes{data:vec![3]}};
+        unsafe {
+            guarded.set_unchecked(4, 1);
+            guarded.set_unchecked(0, 8);
+            assert_eq!(guarded.get_unchecked(0), 8);
+        }
+    }
+
+    #[test]
+    fn qualified_calls_test() {
+        use std::rc::Rc;
+
+        #[hereditary::trait_info]
+        trait Describe
+        {
+            fn describe(&self) -> String;
+            fn convert<T:From<u32>>(&self) -> T;
+            fn scaled<const N:u32>(&self) -> u32;
+            fn upgrade(&mut self, extra:u32);
+        }
+
+        trait Label
+        {
+            fn describe(&self) -> String;
+        }
+
+        struct Engine
+        {
+            power:u32
+        }
+
+        impl Engine
+        {
+            fn describe(&self) -> String {
+                "inherent".to_string()
+            }
+        }
+
+        impl Describe for Engine
+        {
+            fn describe(&self) -> String {
+                format!("engine {}", self.power)
+            }
+
+            fn convert<T:From<u32>>(&self) -> T {
+                T::from(self.power)
+            }
+
+            fn scaled<const N:u32>(&self) -> u32 {
+                self.power*N
+            }
+
+            fn upgrade(&mut self, extra:u32) {
+                self.power += extra;
+            }
+        }
+
+        impl Label for Engine
+        {
+            fn describe(&self) -> String {
+                "label".to_string()
+            }
+        }
+
+        #[derive(hereditary::Forwarding)]
+        struct Car
+        {
+            #[forward_derive(Describe)]
+            engine:Engine
+        }
+
+        // pointer members are dereferenced
+        #[derive(hereditary::Forwarding)]
+        struct Truck
+        {
+            #[forward_derive(Describe)]
+            engine:Box<Engine>
+        }
+
+        struct Bus
+        {
+            engine:Rc<Engine>
+        }
+
+        #[hereditary::forward_trait(engine: Rc<Engine>)]
+        impl Describe for Bus
+        {
+            fn upgrade(&mut self, _extra:u32) {}
+        }
+
+        let mut car = Car{engine:Engine{power:10}};
+        assert_eq!(Describe::describe(&car), "engine 10");
+        assert_eq!((car.engine.describe(), Label::describe(&car.engine)), ("inherent".to_string(), "label".to_string()));
+        assert_eq!(car.convert::<u64>(), 10u64);
+        assert_eq!(car.scaled::<3>(), 30);
+        car.upgrade(5);
+        assert_eq!(Describe::describe(&car), "engine 15");
+
+        let mut truck = Truck{engine:Box::new(Engine{power:2})};
+        truck.upgrade(1);
+        assert_eq!((Describe::describe(&truck), truck.convert::<f64>()), ("engine 3".to_string(), 3.0));
+
+        let bus = Bus{engine:Rc::new(Engine{power:7})};
+        assert_eq!((Describe::describe(&bus), bus.scaled::<2>()), ("engine 7".to_string(), 14));
+    }
+
+    #[test]
+    fn generic_methods_test() {
+
+        #[hereditary::trait_info]
+        trait Registry
+        {
+            fn lookup<'a>(&'a self, key:&'a str) -> &'a str;
+            fn render<T:std::fmt::Display>(&self, value:T, decorate:impl Fn(String) -> String) -> String;
+            fn collect_into<C:FromIterator<u32>>(&self) -> C where Self: Sized;
+            fn visit<F>(&mut self, visitor:F) where F:FnMut(&mut u32), Self: Sized;
+        }
+
+        struct Table
+        {
+            entries:Vec<u32>
+        }
+
+        impl Registry for Table
+        {
+            fn lookup<'a>(&'a self, key:&'a str) -> &'a str {
+                if self.entries.is_empty() {key} else {"table"}
+            }
+
+            fn render<T:std::fmt::Display>(&self, value:T, decorate:impl Fn(String) -> String) -> String {
+                decorate(format!("{}:{}", value, self.entries.len()))
+            }
+
+            fn collect_into<C:FromIterator<u32>>(&self) -> C {
+                self.entries.iter().copied().collect()
+            }
+
+            fn visit<F>(&mut self, visitor:F) where F:FnMut(&mut u32) {
+                self.entries.iter_mut().for_each(visitor);
+            }
+        }
+
+        #[derive(hereditary::Forwarding)]
+        struct Catalog
+        {
+            #[forward_derive(Registry)]
+            table:Table
+        }
+
+        let mut catalog = Catalog{table:Table{entries:vec![1, 2]}};
+        assert_eq!(catalog.lookup("key"), "table");
+        assert_eq!(catalog.render(1.5, |s| format!("<{}>", s)), "<1.5:2>");
+        catalog.visit(|entry| *entry *= 10);
+        let entries:Vec<u32> = catalog.collect_into();
+        assert_eq!(entries, vec![10, 20]);
+        assert_eq!(catalog.collect_into::<std::collections::HashSet<u32>>().len(), 2);
+    }
+}
@@ -107,4 +107,445 @@ mod tests {
         assert_eq!(kimera.altitude(), 50f64);
 
     }
+
+    #[test]
+    fn associated_items_test() {
+
+        #[hereditary::trait_info]
+        trait Source
+        {
+            type Item;
+            const CAPACITY:usize;
+            fn pull(&mut self) -> Option<Self::Item>;
+            fn remaining(&self) -> usize;
+        }
+
+        struct Countdown
+        {
+            count:u32
+        }
+
+        impl Source for Countdown
+        {
+            type Item = u32;
+            const CAPACITY:usize = 8;
+
+            fn pull(&mut self) -> Option<u32> {
+                self.count = self.count.checked_sub(1)?;
+                Some(self.count)
+            }
+
+            fn remaining(&self) -> usize {
+                self.count as usize
+            }
+        }
+
+        #[derive(hereditary::Forwarding)]
+        struct Buffered
+        {
+            #[forward_derive(Source)]
+            source:Box<Countdown>
+        }
+
+        #[derive(hereditary::Forwarding)]
+        #[forward_derive(Source)]
+        enum Feed
+        {
+            Counting(Countdown),
+            Empty(Countdown)
+        }
+
+        fn drain<S:Source>(source:&mut S) -> Vec<S::Item> {
+            std::iter::from_fn(|| source.pull()).take(S::CAPACITY).collect()
+        }
+
+        let mut buffered = Buffered{source:Box::new(Countdown{count:3})};
+        assert_eq!(buffered.remaining(), 3);
+        assert_eq!(drain(&mut buffered), vec![2u32, 1, 0]);
+        assert_eq!(<Buffered as Source>::CAPACITY, 8);
+
+        let mut feed = Feed::Counting(Countdown{count:20});
+        assert_eq!(drain(&mut feed).len(), 8);
+        assert_eq!(feed.remaining(), 12);
+        assert_eq!(Feed::Empty(Countdown{count:0}).remaining(), 0);
+    }
+
+    #[test]
+    fn generic_traits_test() {
+
+        #[hereditary::trait_info]
+        trait Parser<'a, T>
+        {
+            fn parse(&mut self, input:&'a [T]) -> Option<&'a T>;
+            fn parsed(&self) -> usize;
+        }
+
+        #[hereditary::trait_info]
+        trait Encode<Unit, const N:usize = 4>
+        {
+            const WIDTH:[Unit; N];
+            fn encode(&self) -> [Unit; N];
+        }
+
+        #[hereditary::trait_info]
+        trait Collect<I:Iterator>
+        {
+            fn first_of(&mut self, items:I) -> Option<I::Item>;
+        }
+
+        struct Cursor
+        {
+            parsed:usize
+        }
+
+        impl<'a, T> Parser<'a, T> for Cursor
+        {
+            fn parse(&mut self, input:&'a [T]) -> Option<&'a T> {
+                self.parsed += 1;
+                input.first()
+            }
+
+            fn parsed(&self) -> usize {
+                self.parsed
+            }
+        }
+
+        impl Encode<u8> for Cursor
+        {
+            const WIDTH:[u8; 4] = [8; 4];
+
+            fn encode(&self) -> [u8; 4] {
+                (self.parsed as u32).to_le_bytes()
+            }
+        }
+
+        impl Encode<u16, 2> for Cursor
+        {
+            const WIDTH:[u16; 2] = [16; 2];
+
+            fn encode(&self) -> [u16; 2] {
+                [self.parsed as u16, 0]
+            }
+        }
+
+        impl<I:Iterator> Collect<I> for Cursor
+        {
+            fn first_of(&mut self, mut items:I) -> Option<I::Item> {
+                self.parsed += 1;
+                items.next()
+            }
+        }
+
+        // trait arguments can use the generics of the struct
+        #[derive(hereditary::Forwarding)]
+        struct Lexer<'src>
+        {
+            #[forward_derive(Parser<'src, char>, Encode<u8>, Collect<std::str::Chars<'src>>)]
+            cursor:Cursor,
+            source:&'src str
+        }
+
+        // lifetimes that the struct doesn't declare are declared by the implementation
+        #[derive(hereditary::Forwarding)]
+        struct Reader
+        {
+            #[forward_derive(Parser<'a, u32>, Encode<u16, 2>)]
+            cursor:Cursor
+        }
+
+        let chars:Vec<char> = "abc".chars().collect();
+        let mut lexer = Lexer{cursor:Cursor{parsed:0}, source:"xyz"};
+        assert_eq!(lexer.parse(&chars), Some(&'a'));
+        assert_eq!(lexer.first_of(lexer.source.chars()), Some('x'));
+        assert_eq!(Parser::<char>::parsed(&lexer), 2);
+        assert_eq!((lexer.encode(), <Lexer as Encode<u8>>::WIDTH), ([2, 0, 0, 0], [8; 4]));
+
+        let numbers = [7u32, 9];
+        let mut reader = Reader{cursor:Cursor{parsed:0}};
+        assert_eq!(reader.parse(&numbers), Some(&7));
+        assert_eq!((reader.encode(), <Reader as Encode<u16, 2>>::WIDTH), ([1, 0], [16; 2]));
+    }
+
+    #[test]
+    fn method_filters_test() {
+
+        #[hereditary::trait_info]
+        trait Walker
+        {
+            fn name(&self) -> String;
+            fn walk(&mut self, steps:u32) -> u32;
+            fn position(&self) -> u32;
+        }
+
+        struct Legs
+        {
+            steps:u32
+        }
+
+        impl Walker for Legs
+        {
+            fn name(&self) -> String {
+                "legs".to_string()
+            }
+
+            fn walk(&mut self, steps:u32) -> u32 {
+                self.steps += steps;
+                self.steps
+            }
+
+            fn position(&self) -> u32 {
+                self.steps
+            }
+        }
+
+        // excluded methods are implemented by inherent methods
+        #[derive(hereditary::Forwarding)]
+        struct Robot
+        {
+            #[forward_derive(Walker(except(walk = stride, position = odometer)))]
+            legs:Legs,
+            speed:u32
+        }
+
+        impl Robot
+        {
+            fn stride(&mut self, steps:u32) -> u32 {
+                self.legs.walk(steps * self.speed)
+            }
+
+            fn odometer(&self) -> u32 {
+                self.legs.steps * 10
+            }
+        }
+
+        #[derive(hereditary::Forwarding)]
+        #[forward_derive(Walker(only(walk, name = label, position = location)))]
+        enum Vehicle
+        {
+            Walking(Legs),
+            Parked(Legs)
+        }
+
+        impl Vehicle
+        {
+            fn label(&self) -> String {
+                "vehicle".to_string()
+            }
+
+            fn location(&self) -> u32 {
+                match self {
+                    Vehicle::Walking(legs) | Vehicle::Parked(legs) => legs.steps
+                }
+            }
+        }
+
+        fn describe<W:Walker>(walker:&mut W) -> (String, u32, u32) {
+            let walked = walker.walk(2);
+            (walker.name(), walked, walker.position())
+        }
+
+        // bare excluded methods are implemented by the partial implementation, declared after the struct
+        #[derive(hereditary::Forwarding)]
+        struct Courier
+        {
+            #[forward_derive(Walker(except(walk, position = odometer)))]
+            legs:Legs
+        }
+
+        impl Courier
+        {
+            fn odometer(&self) -> u32 {
+                self.legs.steps + 100
+            }
+        }
+
+        #[hereditary::forward_partial]
+        impl Walker for Courier
+        {
+            fn walk(&mut self, steps:u32) -> u32 {
+                self.legs.walk(steps + 1)
+            }
+        }
+
+        #[derive(hereditary::Forwarding)]
+        struct Scout
+        {
+            #[forward_derive(Walker(only(name)))]
+            legs:Legs
+        }
+
+        #[hereditary::forward_partial]
+        impl Walker for Scout
+        {
+            fn walk(&mut self, steps:u32) -> u32 {
+                self.legs.walk(steps) * 2
+            }
+
+            fn position(&self) -> u32 {
+                0
+            }
+        }
+
+        let mut robot = Robot{legs:Legs{steps:0}, speed:3};
+        assert_eq!(describe(&mut robot), ("legs".to_string(), 6, 60));
+
+        let mut courier = Courier{legs:Legs{steps:0}};
+        assert_eq!(describe(&mut courier), ("legs".to_string(), 3, 103));
+
+        let mut scout = Scout{legs:Legs{steps:0}};
+        assert_eq!(describe(&mut scout), ("legs".to_string(), 4, 0));
+
+        let mut vehicle = Vehicle::Walking(Legs{steps:1});
+        assert_eq!(describe(&mut vehicle), ("vehicle".to_string(), 3, 3));
+        assert_eq!(Walker::position(&Vehicle::Parked(Legs{steps:5})), 5);
+    }
+
+    #[test]
+    fn method_mappings_test() {
+
+        #[hereditary::trait_info]
+        trait Shape
+        {
+            fn area(&self) -> f64;
+            fn scale(&mut self, factor:f64);
+            fn unit() -> String;
+        }
+
+        #[hereditary::trait_info]
+        trait Flyer
+        {
+            fn fly(&mut self, elevation:f64) -> f64;
+            fn altitude(&self) -> f64;
+        }
+
+        // component with its own naming
+        struct Square
+        {
+            side:f64
+        }
+
+        impl Square
+        {
+            const DEFAULT_GUST:f64 = 0.5;
+
+            fn surface(&self) -> f64 {
+                self.side * self.side
+            }
+
+            fn resize(&mut self, factor:f64) {
+                self.side *= factor;
+            }
+
+            fn measure() -> String {
+                "m2".to_string()
+            }
+
+            fn ascend(&mut self, elevation:f64, gust:f64) -> f64 {
+                self.side += elevation + gust;
+                self.side
+            }
+
+            fn altitude(&self) -> f64 {
+                self.side
+            }
+        }
+
+        #[derive(hereditary::Forwarding)]
+        struct Tile
+        {
+            #[forward_derive(Shape, map(area = surface, scale = resize, unit = measure))]
+            square:Square
+        }
+
+        struct Kite
+        {
+            frame:Box<Square>
+        }
+
+        #[hereditary::forward_trait(frame, map(fly(elev) = ascend(elev * 2.0, Square::DEFAULT_GUST), altitude = altitude))]
+        impl Flyer for Kite {}
+
+        let mut tile = Tile{square:Square{side:2.0}};
+        tile.scale(1.5);
+        assert_eq!((tile.area(), <Tile as Shape>::unit()), (9.0, "m2".to_string()));
+
+        let mut kite = Kite{frame:Box::new(Square{side:1.0})};
+        assert_eq!(kite.fly(2.0), 5.5);
+        assert_eq!(kite.altitude(), 5.5);
+    }
+
+    #[test]
+    fn method_routes_test() {
+
+        #[hereditary::trait_info]
+        trait Bird
+        {
+            fn sing(&self) -> String;
+            fn fly(&mut self, elevation:f64) -> f64;
+            fn altitude(&self) -> f64;
+        }
+
+        struct Seagull
+        {
+            elevation:f64
+        }
+
+        impl Bird for Seagull
+        {
+            fn sing(&self) -> String {
+                "EEEYA!".into()
+            }
+
+            fn fly(&mut self, elevation:f64) -> f64 {
+                self.elevation += elevation;
+                self.elevation
+            }
+
+            fn altitude(&self) -> f64 {
+                self.elevation
+            }
+        }
+
+        // measures the altitude over the sea level, without implementing the rest of `Bird`
+        struct Physics
+        {
+            ground:f64
+        }
+
+        impl Physics
+        {
+            fn altitude(&self) -> f64 {
+                self.ground
+            }
+
+            fn height(&self) -> f64 {
+                self.ground + 100.0
+            }
+        }
+
+        struct KimeraSphinx
+        {
+            birdpart:Seagull,
+            physics:Physics
+        }
+
+        #[hereditary::forward_trait(birdpart, route(altitude = physics: Physics))]
+        impl Bird for KimeraSphinx {}
+
+        #[derive(hereditary::Forwarding)]
+        struct Griffin
+        {
+            #[forward_derive(Bird, route(altitude = physics), map(altitude = height))]
+            birdpart:Seagull,
+            physics:Physics
+        }
+
+        let mut kimera = KimeraSphinx{birdpart:Seagull{elevation:0.0}, physics:Physics{ground:20.0}};
+        assert_eq!(kimera.fly(5.0), 5.0);
+        assert_eq!((kimera.sing(), kimera.altitude()), ("EEEYA!".to_string(), 20.0));
+
+        let mut griffin = Griffin{birdpart:Seagull{elevation:0.0}, physics:Physics{ground:20.0}};
+        assert_eq!(griffin.fly(5.0), 5.0);
+        assert_eq!(griffin.altitude(), 120.0);
+    }
 }